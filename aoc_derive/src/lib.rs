@@ -0,0 +1,179 @@
+//! Derive macros for the `aoc-2022` crate.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Fields, LitChar, Type};
+
+/// Maps the variants of an enum to the characters of a puzzle grid.
+///
+/// Every unit variant is annotated with `#[tile('c')]`. A variant holding a single value of
+/// another tile type is left unannotated and takes that type's characters. The enum gets a
+/// `TryFrom<char>` failing with `aoc_2022::tile::UnknownTile`, a `Display` writing the
+/// character back, and an `ALL` constant listing every tile in declaration order, which needs
+/// the enum to be `Copy` when it nests another tile type.
+///
+/// ```ignore
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, Tile)]
+/// enum Material {
+///   #[tile('.')]
+///   Air,
+///   #[tile('#')]
+///   Rock,
+/// }
+/// ```
+#[proc_macro_derive(Tile, attributes(tile))]
+pub fn derive_tile(input: TokenStream) -> TokenStream {
+  let input = parse_macro_input!(input as DeriveInput);
+  expand(&input)
+    .unwrap_or_else(Error::into_compile_error)
+    .into()
+}
+
+enum Variant<'a> {
+  Unit(&'a syn::Ident, LitChar),
+  Nested(&'a syn::Ident, &'a Type),
+}
+
+fn variants(input: &DeriveInput) -> syn::Result<Vec<Variant<'_>>> {
+  let Data::Enum(data) = &input.data else {
+    return Err(Error::new(
+      input.span(),
+      "Tile can only be derived for enums",
+    ));
+  };
+
+  data
+    .variants
+    .iter()
+    .map(|variant| {
+      let attr = variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("tile"));
+      match (&variant.fields, attr) {
+        (Fields::Unit, Some(attr)) => Ok(Variant::Unit(&variant.ident, attr.parse_args()?)),
+        (Fields::Unit, None) => Err(Error::new(
+          variant.span(),
+          "missing #[tile('c')] with the character of this variant",
+        )),
+        (Fields::Unnamed(fields), None) if fields.unnamed.len() == 1 => {
+          Ok(Variant::Nested(&variant.ident, &fields.unnamed[0].ty))
+        }
+        _ => Err(Error::new(
+          variant.span(),
+          "tile variants are either units with #[tile('c')] or hold a single other tile",
+        )),
+      }
+    })
+    .collect()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+  let name = &input.ident;
+  if !input.generics.params.is_empty() {
+    return Err(Error::new(
+      input.generics.span(),
+      "Tile cannot be derived for generic enums",
+    ));
+  }
+  let variants = variants(input)?;
+
+  let try_from = variants.iter().map(|variant| match variant {
+    Variant::Unit(ident, c) => quote! {
+      if value == #c {
+        return Ok(#name::#ident);
+      }
+    },
+    Variant::Nested(ident, ty) => quote! {
+      if let Ok(inner) = <#ty as TryFrom<char>>::try_from(value) {
+        return Ok(#name::#ident(inner));
+      }
+    },
+  });
+
+  let display = variants.iter().map(|variant| match variant {
+    Variant::Unit(ident, c) => quote! { #name::#ident => write!(f, "{}", #c) },
+    Variant::Nested(ident, _) => quote! { #name::#ident(inner) => write!(f, "{}", inner) },
+  });
+
+  let units = variants
+    .iter()
+    .filter_map(|variant| match variant {
+      Variant::Unit(ident, _) => Some(ident),
+      Variant::Nested(..) => None,
+    })
+    .collect::<Vec<_>>();
+  let nested = variants
+    .iter()
+    .filter_map(|variant| match variant {
+      Variant::Nested(ident, ty) => Some((ident, ty)),
+      Variant::Unit(..) => None,
+    })
+    .collect::<Vec<_>>();
+
+  let all = if nested.is_empty() {
+    quote! { &[#(#name::#units),*] }
+  } else {
+    // Nested tiles are only known by their own `ALL`, so the list is filled in at compile time.
+    let unit_count = units.len();
+    let nested_lengths = nested.iter().map(|(_, ty)| quote! { + #ty::ALL.len() });
+    let fill_nested = nested.iter().map(|(ident, ty)| {
+      quote! {
+        let mut inner = 0;
+        while inner < #ty::ALL.len() {
+          all[next] = #name::#ident(#ty::ALL[inner]);
+          next += 1;
+          inner += 1;
+        }
+      }
+    });
+    let first = match (units.first(), nested.first()) {
+      (Some(unit), _) => quote! { #name::#unit },
+      (None, Some((ident, ty))) => quote! { #name::#ident(#ty::ALL[0]) },
+      (None, None) => unreachable!(),
+    };
+    quote! {
+      &{
+        const LEN: usize = #unit_count #(#nested_lengths)*;
+        let mut all = [#first; LEN];
+        let mut next = 0;
+        #(
+          all[next] = #name::#units;
+          next += 1;
+        )*
+        #(#fill_nested)*
+        let _ = next;
+        all
+      }
+    }
+  };
+
+  Ok(quote! {
+    impl TryFrom<char> for #name {
+      type Error = ::aoc_2022::tile::UnknownTile;
+
+      fn try_from(value: char) -> Result<Self, Self::Error> {
+        #(#try_from)*
+        Err(::aoc_2022::tile::UnknownTile {
+          tile: stringify!(#name),
+          found: value,
+        })
+      }
+    }
+
+    impl ::std::fmt::Display for #name {
+      fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+          #(#display,)*
+        }
+      }
+    }
+
+    impl #name {
+      /// Every tile, in declaration order.
+      #[allow(dead_code)]
+      pub const ALL: &'static [#name] = #all;
+    }
+  })
+}
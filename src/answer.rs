@@ -0,0 +1,137 @@
+//! A single type for the answers of every part, whatever type the part itself returns.
+
+use std::{
+  convert::Infallible,
+  fmt::Display,
+  hash::{Hash, Hasher},
+  str::FromStr,
+};
+
+/// The answer of a part, either a number or text such as day 25's SNAFU number.
+///
+/// Answers compare by their canonical form, so `Int(42)` equals `Text("42\n")`, and rendered
+/// answers equal each other regardless of trailing whitespace on their lines.
+#[derive(Debug, Clone)]
+pub enum Answer {
+  Int(i128),
+  Text(String),
+}
+
+impl Answer {
+  /// Whether the answer is drawn over several lines, like the letters of day 10, and has to
+  /// be read off by eye.
+  pub fn is_rendered(&self) -> bool {
+    matches!(self, Answer::Text(text) if text.trim().contains('\n'))
+  }
+
+  /// The form answers are compared by: numbers stored as text become [`Answer::Int`], and
+  /// text loses its surrounding blank space and the trailing spaces of its lines.
+  pub fn normalized(&self) -> Answer {
+    match self {
+      Answer::Int(int) => Answer::Int(*int),
+      Answer::Text(text) => match text.trim().parse() {
+        Ok(int) => Answer::Int(int),
+        Err(_) => Answer::Text(
+          text
+            .trim_matches('\n')
+            .lines()
+            .map(str::trim_end)
+            .collect::<Vec<_>>()
+            .join("\n"),
+        ),
+      },
+    }
+  }
+}
+
+impl Display for Answer {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Answer::Int(int) => write!(f, "{int}"),
+      Answer::Text(text) => write!(f, "{text}"),
+    }
+  }
+}
+
+impl PartialEq for Answer {
+  fn eq(&self, other: &Self) -> bool {
+    match (self.normalized(), other.normalized()) {
+      (Answer::Int(a), Answer::Int(b)) => a == b,
+      (Answer::Text(a), Answer::Text(b)) => a == b,
+      _ => false,
+    }
+  }
+}
+
+impl Eq for Answer {}
+
+impl Hash for Answer {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    match self.normalized() {
+      Answer::Int(int) => int.hash(state),
+      Answer::Text(text) => text.hash(state),
+    }
+  }
+}
+
+macro_rules! from_int {
+  ($($int:ty),*) => {
+    $(
+      impl From<$int> for Answer {
+        fn from(value: $int) -> Self {
+          Answer::Int(value.into())
+        }
+      }
+    )*
+  };
+}
+
+from_int!(u8, u16, u32, u64, i8, i16, i32, i64, i128);
+
+impl From<usize> for Answer {
+  fn from(value: usize) -> Self {
+    Answer::Int(value as i128)
+  }
+}
+
+impl From<String> for Answer {
+  fn from(value: String) -> Self {
+    Answer::Text(value)
+  }
+}
+
+impl From<&str> for Answer {
+  fn from(value: &str) -> Self {
+    Answer::Text(value.to_string())
+  }
+}
+
+/// Reads a stored answer back, numbers becoming [`Answer::Int`].
+impl FromStr for Answer {
+  type Err = Infallible;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Ok(Answer::from(s).normalized())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_normalized_comparison() {
+    assert_eq!(Answer::from(42u32), Answer::from(" 42\n"));
+    assert_eq!(Answer::from(-3i64), "-3".parse().unwrap());
+    assert_ne!(Answer::from(42usize), Answer::from("2=-1=0"));
+    assert_eq!(Answer::from("#..#  \n.##.\n"), Answer::from("#..#\n.##."));
+    assert_eq!("2=-1=0".parse::<Answer>().unwrap().to_string(), "2=-1=0");
+  }
+
+  #[test]
+  fn test_rendered() {
+    assert!(Answer::from("#..#\n.##.\n").is_rendered());
+    assert!(!Answer::from("2=-1=0\n").is_rendered());
+    assert!(!Answer::from(7u8).is_rendered());
+  }
+}
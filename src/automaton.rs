@@ -0,0 +1,329 @@
+//! Generation-by-generation simulations of cells that look at their neighbours and move.
+
+use std::collections::{HashMap, HashSet};
+
+/// A cell position as `[x, y]`, `y` growing downwards.
+pub type Point = [i32; 2];
+
+/// Storage for the occupied cells of the world.
+pub trait Backend<T> {
+  fn get(&self, point: Point) -> Option<&T>;
+
+  fn insert(&mut self, point: Point, cell: T);
+
+  fn remove(&mut self, point: Point) -> Option<T>;
+
+  /// Every occupied point, in no particular order.
+  fn points(&self) -> Vec<Point>;
+}
+
+/// An unbounded world keeping only its occupied cells.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Sparse<T>(pub HashMap<Point, T>);
+
+impl<T> Backend<T> for Sparse<T> {
+  fn get(&self, point: Point) -> Option<&T> {
+    self.0.get(&point)
+  }
+
+  fn insert(&mut self, point: Point, cell: T) {
+    self.0.insert(point, cell);
+  }
+
+  fn remove(&mut self, point: Point) -> Option<T> {
+    self.0.remove(&point)
+  }
+
+  fn points(&self) -> Vec<Point> {
+    self.0.keys().copied().collect()
+  }
+}
+
+impl<T> FromIterator<(Point, T)> for Sparse<T> {
+  fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+    Self(iter.into_iter().collect())
+  }
+}
+
+/// A world of `width` by `height` cells starting at `[0, 0]`. Everything outside of it reads
+/// as empty, and placing a cell there panics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dense<T> {
+  width: usize,
+  height: usize,
+  cells: Vec<Option<T>>,
+}
+
+impl<T> Dense<T> {
+  pub fn new(width: usize, height: usize) -> Self {
+    Self {
+      width,
+      height,
+      cells: std::iter::repeat_with(|| None)
+        .take(width * height)
+        .collect(),
+    }
+  }
+
+  fn index(&self, [x, y]: Point) -> Option<usize> {
+    let (x, y) = (usize::try_from(x).ok()?, usize::try_from(y).ok()?);
+    (x < self.width && y < self.height).then_some(y * self.width + x)
+  }
+}
+
+impl<T> Backend<T> for Dense<T> {
+  fn get(&self, point: Point) -> Option<&T> {
+    self.cells[self.index(point)?].as_ref()
+  }
+
+  fn insert(&mut self, point: Point, cell: T) {
+    let index = self
+      .index(point)
+      .unwrap_or_else(|| panic!("{point:?} is outside of the world"));
+    self.cells[index] = Some(cell);
+  }
+
+  fn remove(&mut self, point: Point) -> Option<T> {
+    let index = self.index(point)?;
+    self.cells[index].take()
+  }
+
+  fn points(&self) -> Vec<Point> {
+    self
+      .cells
+      .iter()
+      .enumerate()
+      .filter(|(_, cell)| cell.is_some())
+      .map(|(index, _)| [(index % self.width) as i32, (index / self.width) as i32])
+      .collect()
+  }
+}
+
+/// The cells around a point, as seen at the start of a round.
+pub struct Neighbourhood<'a, T> {
+  world: &'a dyn Backend<T>,
+  center: Point,
+}
+
+impl<T> Neighbourhood<'_, T> {
+  /// The cell at the given offset from the center.
+  pub fn get(&self, [dx, dy]: Point) -> Option<&T> {
+    self.world.get([self.center[0] + dx, self.center[1] + dy])
+  }
+
+  pub fn is_occupied(&self, offset: Point) -> bool {
+    self.get(offset).is_some()
+  }
+
+  /// Whether none of the eight surrounding cells is occupied.
+  pub fn is_alone(&self) -> bool {
+    (-1..=1)
+      .flat_map(|dy| (-1..=1).map(move |dx| [dx, dy]))
+      .filter(|&offset| offset != [0, 0])
+      .all(|offset| !self.is_occupied(offset))
+  }
+}
+
+/// What a cell does in a round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+  Stay,
+  /// Moves by the given offset, unless another cell wants to move there too.
+  Move(Point),
+  Vanish,
+}
+
+/// How every cell behaves.
+pub trait Rule {
+  type Cell;
+
+  /// Decides what the cell at `point` does in `round`, counting from 0.
+  fn act(
+    &self,
+    point: Point,
+    cell: &Self::Cell,
+    neighbourhood: &Neighbourhood<Self::Cell>,
+    round: usize,
+  ) -> Action;
+
+  /// Picks which of the cells moving to the same destination gets there, as an index into
+  /// `contenders`. By default none of them moves.
+  fn resolve(&self, _destination: Point, _contenders: &[Point]) -> Option<usize> {
+    None
+  }
+}
+
+/// A world of cells following a rule.
+pub struct Automaton<R: Rule, B> {
+  pub rule: R,
+  pub world: B,
+  /// Rounds simulated so far.
+  pub round: usize,
+}
+
+impl<R: Rule, B: Backend<R::Cell>> Automaton<R, B> {
+  pub fn new(rule: R, world: B) -> Self {
+    Self {
+      rule,
+      world,
+      round: 0,
+    }
+  }
+
+  /// Simulates a single round and returns how many cells moved or vanished.
+  ///
+  /// Every cell decides based on the world as it was at the start of the round. A cell may
+  /// move onto a cell that leaves in the same round, but not onto one that stays.
+  pub fn step(&mut self) -> usize {
+    let mut points = self.world.points();
+    points.sort_unstable();
+
+    let mut moves = HashMap::<Point, Vec<Point>>::new();
+    let mut vanishing = HashSet::new();
+    for &point in &points {
+      let neighbourhood = Neighbourhood {
+        world: &self.world,
+        center: point,
+      };
+      let cell = self.world.get(point).unwrap();
+      match self.rule.act(point, cell, &neighbourhood, self.round) {
+        Action::Stay => (),
+        Action::Move([dx, dy]) => moves
+          .entry([point[0] + dx, point[1] + dy])
+          .or_default()
+          .push(point),
+        Action::Vanish => {
+          vanishing.insert(point);
+        }
+      }
+    }
+
+    let mut moving = moves
+      .into_iter()
+      .filter_map(|(destination, contenders)| match contenders.as_slice() {
+        &[from] => Some((from, destination)),
+        _ => {
+          let winner = self.rule.resolve(destination, &contenders)?;
+          Some((contenders[winner], destination))
+        }
+      })
+      .collect::<HashMap<_, _>>();
+
+    // Moves onto a cell that stays are blocked, which may in turn block moves onto the
+    // blocked cell.
+    loop {
+      let blocked = moving
+        .iter()
+        .filter(|(_, destination)| {
+          self.world.get(**destination).is_some()
+            && !moving.contains_key(*destination)
+            && !vanishing.contains(*destination)
+        })
+        .map(|(&from, _)| from)
+        .collect::<Vec<_>>();
+      if blocked.is_empty() {
+        break;
+      }
+      for from in blocked {
+        moving.remove(&from);
+      }
+    }
+
+    for &point in &vanishing {
+      self.world.remove(point);
+    }
+    let cells = moving
+      .iter()
+      .map(|(&from, &destination)| (destination, self.world.remove(from).unwrap()))
+      .collect::<Vec<_>>();
+    for (destination, cell) in cells {
+      self.world.insert(destination, cell);
+    }
+
+    self.round += 1;
+    moving.len() + vanishing.len()
+  }
+
+  /// Simulates the given number of rounds.
+  pub fn run(&mut self, rounds: usize) {
+    for _ in 0..rounds {
+      self.step();
+    }
+  }
+
+  /// Simulates until a round in which nothing changes, and returns that round's number,
+  /// counting from 1.
+  pub fn run_until_stable(&mut self) -> usize {
+    while self.step() > 0 {}
+    self.round
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// Cells move right until they are blocked or reach the fourth column.
+  struct Right;
+
+  impl Rule for Right {
+    type Cell = char;
+
+    fn act(
+      &self,
+      [x, _]: Point,
+      _: &char,
+      neighbourhood: &Neighbourhood<char>,
+      _: usize,
+    ) -> Action {
+      match x == 3 || neighbourhood.is_occupied([1, 0]) {
+        true => Action::Stay,
+        false => Action::Move([1, 0]),
+      }
+    }
+  }
+
+  /// Cells move diagonally towards the middle column, with the left one winning.
+  struct Funnel;
+
+  impl Rule for Funnel {
+    type Cell = char;
+
+    fn act(&self, [x, _]: Point, _: &char, _: &Neighbourhood<char>, round: usize) -> Action {
+      match (round, x) {
+        (0, 0) => Action::Move([1, 1]),
+        (0, 2) => Action::Move([-1, 1]),
+        _ => Action::Stay,
+      }
+    }
+
+    fn resolve(&self, _: Point, contenders: &[Point]) -> Option<usize> {
+      (0..contenders.len()).min_by_key(|&idx| contenders[idx])
+    }
+  }
+
+  #[test]
+  fn test_dense() {
+    let mut world = Dense::new(4, 1);
+    world.insert([0, 0], 'a');
+    world.insert([1, 0], 'b');
+    let mut automaton = Automaton::new(Right, world);
+
+    assert_eq!(automaton.step(), 1);
+    assert_eq!(automaton.world.points(), [[0, 0], [2, 0]]);
+    assert_eq!(automaton.run_until_stable(), 4);
+    assert_eq!(automaton.world.points(), [[2, 0], [3, 0]]);
+  }
+
+  #[test]
+  fn test_sparse() {
+    let world = Sparse::from_iter([([0, 0], 'a'), ([2, 0], 'b')]);
+    let mut automaton = Automaton::new(Funnel, world);
+
+    assert_eq!(automaton.run_until_stable(), 2);
+    let mut points = automaton.world.points();
+    points.sort();
+    assert_eq!(points, [[1, 1], [2, 0]]);
+    assert_eq!(automaton.world.get([1, 1]), Some(&'a'));
+  }
+}
@@ -0,0 +1,219 @@
+use std::{
+  alloc::{GlobalAlloc, Layout, System},
+  fmt::Display,
+  sync::atomic::{AtomicUsize, Ordering},
+  time::{Duration, Instant},
+};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// Wraps the system allocator and counts every allocation made through it.
+///
+/// The counters are only updated when a binary installs it:
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: CountingAllocator = CountingAllocator;
+/// ```
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+  fn grow(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED.fetch_add(size, Ordering::Relaxed);
+    let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(live, Ordering::Relaxed);
+  }
+
+  fn shrink(size: usize) {
+    LIVE.fetch_sub(size, Ordering::Relaxed);
+  }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    let ptr = System.alloc(layout);
+    if !ptr.is_null() {
+      Self::grow(layout.size());
+    }
+    ptr
+  }
+
+  unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+    let ptr = System.alloc_zeroed(layout);
+    if !ptr.is_null() {
+      Self::grow(layout.size());
+    }
+    ptr
+  }
+
+  unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    System.dealloc(ptr, layout);
+    Self::shrink(layout.size());
+  }
+
+  unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+    let new_ptr = System.realloc(ptr, layout, new_size);
+    if !new_ptr.is_null() {
+      Self::shrink(layout.size());
+      Self::grow(new_size);
+    }
+    new_ptr
+  }
+}
+
+/// Timing and memory usage of a single solver run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Measurement {
+  pub duration: Duration,
+  /// Number of allocations, every reallocation counting as a new one.
+  pub allocations: usize,
+  /// Total bytes requested from the allocator.
+  pub allocated: usize,
+  /// Highest amount of live memory on top of what was live before the run.
+  pub peak: usize,
+}
+
+impl Display for Measurement {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{:?}, {} allocations, {} allocated, {} peak",
+      self.duration,
+      self.allocations,
+      Bytes(self.allocated),
+      Bytes(self.peak)
+    )
+  }
+}
+
+struct Bytes(usize);
+
+impl Display for Bytes {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if self.0 < 1024 {
+      return write!(f, "{} B", self.0);
+    }
+    let mut size = self.0 as f64;
+    let mut unit = "B";
+    for next in UNITS {
+      if size < 1024.0 {
+        break;
+      }
+      size /= 1024.0;
+      unit = next;
+    }
+    write!(f, "{size:.1} {unit}")
+  }
+}
+
+/// Runs `f` once and records how long it took and what it allocated.
+///
+/// Allocation counts stay at zero unless [`CountingAllocator`] is the global allocator.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Measurement) {
+  let baseline = LIVE.load(Ordering::Relaxed);
+  let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+  let allocated = ALLOCATED.load(Ordering::Relaxed);
+  PEAK.store(baseline, Ordering::Relaxed);
+
+  let start = Instant::now();
+  let result = f();
+  let duration = start.elapsed();
+
+  let measurement = Measurement {
+    duration,
+    allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+    allocated: ALLOCATED.load(Ordering::Relaxed) - allocated,
+    peak: PEAK.load(Ordering::Relaxed).saturating_sub(baseline),
+  };
+  (result, measurement)
+}
+
+/// Measures a single part and prints its answer next to the measurement.
+pub fn run<T: Display>(label: &str, f: impl FnOnce() -> T) -> T {
+  let (result, measurement) = measure(f);
+  println!("{label}: {result} ({measurement})");
+  result
+}
+
+/// The fastest of several runs of one implementation, and its answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comparison<T> {
+  pub name: String,
+  pub answer: T,
+  pub fastest: Measurement,
+}
+
+/// Runs every implementation `runs` times on the same input and prints their fastest runs
+/// side by side, flagging answers that differ from the first implementation's.
+pub fn compare<T: Display + PartialEq>(
+  label: &str,
+  implementations: &[(&str, &dyn Fn() -> T)],
+  runs: usize,
+) -> Vec<Comparison<T>> {
+  let mut comparisons: Vec<Comparison<T>> = vec![];
+  println!("{label}");
+  for &(name, implementation) in implementations {
+    let (answer, fastest) = (0..runs.max(1))
+      .map(|_| measure(implementation))
+      .min_by_key(|(_, measurement)| measurement.duration)
+      .unwrap();
+    let disagrees = comparisons
+      .first()
+      .is_some_and(|first| first.answer != answer);
+    println!(
+      "  {name:<12} {answer} ({fastest}){}",
+      if disagrees { " differs" } else { "" }
+    );
+    comparisons.push(Comparison {
+      name: name.to_string(),
+      answer,
+      fastest,
+    });
+  }
+  comparisons
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[global_allocator]
+  static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+  #[test]
+  fn test_bytes() {
+    assert_eq!(Bytes(512).to_string(), "512 B");
+    assert_eq!(Bytes(1536).to_string(), "1.5 KiB");
+    assert_eq!(Bytes(3 * 1024 * 1024).to_string(), "3.0 MiB");
+  }
+
+  #[test]
+  fn test_compare() {
+    let comparisons = compare(
+      "Sum",
+      &[
+        ("loop", &|| (1..=100).sum::<u32>()),
+        ("formula", &|| 100 * 101 / 2),
+        ("wrong", &|| 0),
+      ],
+      3,
+    );
+    assert_eq!(comparisons.len(), 3);
+    assert_eq!(comparisons[1].answer, 5050);
+    assert_eq!(comparisons[2].name, "wrong");
+  }
+
+  #[test]
+  fn test_measure() {
+    let (len, measurement) = measure(|| vec![0u8; 4096].len());
+    assert_eq!(len, 4096);
+    assert!(measurement.allocations >= 1);
+    assert!(measurement.allocated >= 4096);
+  }
+}
@@ -0,0 +1,642 @@
+mod repl;
+
+use std::{
+  collections::HashMap,
+  fs,
+  io::{self, BufRead, Write},
+  path::PathBuf,
+  process::{self, ExitCode},
+  time::Duration,
+};
+
+use clap::{Parser, Subcommand, ValueEnum};
+
+use aoc_2022::{
+  answer::Answer,
+  bench::{self, CountingAllocator},
+  cancel::Cancellation,
+  config::{self, Config},
+  history::{self, Record},
+  matrix::{self, Matrix, Outcome},
+  registry, samples,
+  scaling::{self, Rng, Series},
+  search::BoundStats,
+  status::{self, Calendar},
+  vault::{self, Vault, VaultError},
+};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2022 solutions")]
+struct Cli {
+  /// Configuration file with input paths, output format and per-day parameters.
+  #[arg(long, global = true, default_value = config::PATH)]
+  config: PathBuf,
+  #[command(subcommand)]
+  command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+  /// Runs every implemented part of a day on its puzzle input.
+  Run {
+    day: u8,
+    /// Also prints the intermediate results of the solver.
+    #[arg(long, value_enum, num_args = 0..=1, default_missing_value = "tree")]
+    explain: Option<Format>,
+    /// Seconds after which searching parts settle for their best answer so far.
+    #[arg(long, value_name = "SECONDS")]
+    time_limit: Option<f64>,
+    /// Runs the implementation with this name instead of the default one, for the parts
+    /// that have it.
+    #[arg(long = "impl", value_name = "NAME")]
+    implementation: Option<String>,
+    /// Also prints how well the searches of the day pruned, as counted by the runs giving
+    /// the answers.
+    #[arg(long, conflicts_with = "implementation")]
+    stats: bool,
+  },
+  /// Compares every implementation of a day's parts on its puzzle input.
+  Bench {
+    day: u8,
+    /// Runs of each implementation, of which the fastest is reported.
+    #[arg(long, default_value_t = 10)]
+    runs: usize,
+    /// Times the implementations on generated inputs of doubling sizes instead, and fits how
+    /// their time grows with the size.
+    #[arg(long)]
+    scale: bool,
+    /// Number of sizes to generate inputs of.
+    #[arg(long, default_value_t = 5, requires = "scale")]
+    steps: u32,
+    /// Where to write the timings for plotting. Defaults to `target/aoc-scaling-dayN.dat`.
+    #[arg(long, requires = "scale")]
+    plot: Option<PathBuf>,
+  },
+  /// Shows how the timings and answers of a day changed across runs.
+  History { day: u8 },
+  /// Extracts a day's sample input from a saved puzzle page.
+  Samples {
+    day: u8,
+    /// The puzzle page, saved from the browser.
+    page: PathBuf,
+    /// Which code block of the page is the sample, counting from 1. Asked for if missing.
+    #[arg(long)]
+    block: Option<usize>,
+    /// Where to write the sample. Defaults to `src/dayN/sample` for the days kept in a
+    /// directory, and is needed for the others, whose sample is inline. The answer candidates
+    /// of the page go next to it, in `<output>.candidates`.
+    #[arg(long)]
+    output: Option<PathBuf>,
+  },
+  /// Solves inputs typed or pasted at a prompt.
+  Repl {
+    /// The day to start with, also picked with `:day N`.
+    day: Option<u8>,
+    /// Solves only this part, also picked with `:part N`.
+    #[arg(long)]
+    part: Option<u8>,
+  },
+  /// Runs every day on everyone's inputs, laid out as `<dir>/<user>/day_N`, and checks the
+  /// answers against the `day_N.answers` files next to them.
+  Matrix {
+    dir: PathBuf,
+    /// Seconds each searching part may take on an input, past which it is reported as timed
+    /// out with its best answer so far.
+    #[arg(long, value_name = "SECONDS", default_value_t = 60.0)]
+    time_limit: f64,
+  },
+  /// Shows which parts are implemented, pass their sample tests, have a stored answer and how
+  /// long they took last time.
+  Status {
+    /// Leaves out the sample tests instead of running them with `cargo test`.
+    #[arg(long)]
+    no_tests: bool,
+  },
+  /// Manages the encrypted puzzle inputs.
+  #[command(subcommand)]
+  Vault(VaultCommand),
+}
+
+#[derive(Subcommand)]
+enum VaultCommand {
+  /// Prints a new random key, to be stored outside the repository.
+  Keygen,
+  /// Encrypts the inputs of the given days, or of every day, next to the plain ones.
+  Encrypt { days: Vec<u8> },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Format {
+  Tree,
+  Json,
+}
+
+struct RunOptions {
+  explain: Option<Format>,
+  time_limit: Option<Duration>,
+  implementation: Option<String>,
+  stats: bool,
+}
+
+fn run(config: &Config, day: u8, options: RunOptions) -> Result<(), String> {
+  let RunOptions {
+    explain,
+    time_limit,
+    implementation,
+    stats,
+  } = options;
+  let day = registry::get(day).ok_or_else(|| format!("day {day} does not exist"))?;
+  if let Some(name) = &implementation {
+    if !day
+      .parts()
+      .any(|(number, _, _)| day.implementation(number, name).is_some())
+    {
+      return Err(format!(
+        "day {} has no implementation called {name}",
+        day.day
+      ));
+    }
+  }
+  if stats && day.parts().all(|(_, _, within)| within.is_none()) {
+    return Err(format!("day {} has no search statistics", day.day));
+  }
+  let input = day
+    .read_input(config)
+    .map_err(|err| format!("cannot read the input: {err}"))?;
+
+  let json = config.output.format == config::Format::Json;
+  let mut searches = vec![];
+  if !json {
+    println!("Day {}: {}", day.day, day.title);
+  }
+  for (number, part, within) in day.parts() {
+    let chosen = implementation
+      .as_deref()
+      .and_then(|name| Some((name, day.implementation(number, name)?)))
+      .filter(|&(name, _)| name != registry::DEFAULT_IMPLEMENTATION);
+    let (answer, optimal, measurement) = match (chosen, time_limit, within) {
+      (Some((name, part)), _, _) => {
+        let (answer, measurement) = bench::measure(|| part(&input, config));
+        if !json {
+          println!("Part {number} ({name}): {answer} ({measurement})");
+        }
+        (answer, true, measurement)
+      }
+      (None, _, Some(within)) if time_limit.is_some() || stats => {
+        let cancellation = time_limit.map_or_else(Cancellation::new, Cancellation::after);
+        let mut search = BoundStats::start();
+        let (best, measurement) = bench::measure(|| within(&input, &cancellation, &mut search));
+        if !json {
+          println!("Part {number}: {best} ({measurement})");
+        }
+        searches.push((number, search));
+        (best.value, best.optimal, measurement)
+      }
+      (None, _, _) => {
+        let (answer, measurement) = bench::measure(|| part(&input, config));
+        if !json {
+          println!("Part {number}: {answer} ({measurement})");
+        }
+        (answer, true, measurement)
+      }
+    };
+    if json {
+      let output = serde_json::json!({
+        "day": day.day,
+        "part": number,
+        "implementation": chosen.map_or(registry::DEFAULT_IMPLEMENTATION, |(name, _)| name),
+        "answer": answer.to_string(),
+        "duration_ns": measurement.duration.as_nanos() as u64,
+        "allocations": measurement.allocations,
+        "allocated": measurement.allocated,
+        "peak": measurement.peak,
+      });
+      println!("{output}");
+    }
+    // The history follows the default implementations only, to compare like with like.
+    if chosen.is_none() {
+      let record = Record::new(day.day, number, &answer, optimal, measurement.duration);
+      if let Err(err) = history::append(history::PATH, &record) {
+        eprintln!("warning: cannot record the run in {}: {err}", history::PATH);
+      }
+    }
+  }
+
+  if stats {
+    for (number, stats) in searches {
+      if json {
+        let output = serde_json::json!({
+          "day": day.day,
+          "part": number,
+          "expanded": stats.expanded,
+          "pruned": stats.pruned,
+          "max_depth": stats.max_depth,
+          "improvements": stats.improvements,
+          "first_solution_ns": stats.first_solution.map(|elapsed| elapsed.as_nanos() as u64),
+        });
+        println!("{output}");
+      } else {
+        println!("Part {number} search: {stats}");
+      }
+    }
+  }
+
+  if let Some(format) = explain {
+    let explain = day
+      .explain
+      .ok_or_else(|| format!("day {} cannot explain its answers", day.day))?;
+    let trace = explain(&input, config);
+    match format {
+      Format::Tree => print!("{trace}"),
+      Format::Json => println!("{}", trace.to_json()),
+    }
+  }
+
+  Ok(())
+}
+
+fn compare(config: &Config, day: u8, runs: usize) -> Result<(), String> {
+  let day = registry::get(day).ok_or_else(|| format!("day {day} does not exist"))?;
+  let input = day
+    .read_input(config)
+    .map_err(|err| format!("cannot read the input: {err}"))?;
+
+  let input = input.as_str();
+
+  println!("Day {}: {}", day.day, day.title);
+  for (number, _, _) in day.parts() {
+    let solvers = day
+      .implementations(number)
+      .into_iter()
+      .map(|(name, solver)| (name, move || solver(input, config)))
+      .collect::<Vec<_>>();
+    let implementations = solvers
+      .iter()
+      .map(|(name, solver)| (*name, solver as &dyn Fn() -> Answer))
+      .collect::<Vec<_>>();
+    bench::compare(&format!("Part {number}"), &implementations, runs);
+  }
+  Ok(())
+}
+
+fn measure_scaling(
+  config: &Config,
+  day: u8,
+  runs: usize,
+  steps: u32,
+  plot: Option<PathBuf>,
+) -> Result<(), String> {
+  let day = registry::get(day).ok_or_else(|| format!("day {day} does not exist"))?;
+  let generator = day
+    .generator
+    .ok_or_else(|| format!("day {} cannot generate inputs", day.day))?;
+  let inputs = generator
+    .sizes(steps)
+    .into_iter()
+    .map(|size| (size, (generator.generate)(size, &mut Rng::new(size as u64))))
+    .collect::<Vec<_>>();
+
+  println!("Day {}: {}, size in {}", day.day, day.title, generator.unit);
+  let mut all = vec![];
+  for (number, _, _) in day.parts() {
+    for (name, solver) in day.implementations(number) {
+      let series = Series {
+        label: format!("part_{number}_{name}"),
+        runs: inputs
+          .iter()
+          .map(|(size, input)| {
+            let fastest = (0..runs.max(1))
+              .map(|_| bench::measure(|| solver(input, config)).1.duration)
+              .min()
+              .unwrap();
+            (*size, fastest)
+          })
+          .collect(),
+      };
+      print!("{series}");
+      all.push(series);
+    }
+  }
+
+  let plot =
+    plot.unwrap_or_else(|| PathBuf::from(format!("target/aoc-scaling-day{}.dat", day.day)));
+  if let Some(parent) = plot.parent() {
+    fs::create_dir_all(parent)
+      .map_err(|err| format!("cannot create {}: {err}", parent.display()))?;
+  }
+  fs::write(&plot, scaling::plot_data(generator.unit, &all))
+    .map_err(|err| format!("cannot write {}: {err}", plot.display()))?;
+  println!("Wrote the timings to {}", plot.display());
+  Ok(())
+}
+
+fn show_history(day: u8) -> Result<(), String> {
+  let records =
+    history::load(history::PATH).map_err(|err| format!("cannot read {}: {err}", history::PATH))?;
+  let trends = history::trends(&records, day);
+  if trends.is_empty() {
+    return Err(format!("day {day} has not been run yet"));
+  }
+  for trend in trends {
+    print!("{trend}");
+  }
+  Ok(())
+}
+
+fn pick_block(blocks: &[String]) -> Result<usize, String> {
+  for (idx, block) in blocks.iter().enumerate() {
+    let first_line = block.lines().next().unwrap_or_default();
+    println!(
+      "{:>3}: {first_line:?} ({} lines)",
+      idx + 1,
+      block.lines().count()
+    );
+  }
+  print!("Sample block: ");
+  io::stdout().flush().map_err(|err| err.to_string())?;
+
+  let mut choice = String::new();
+  io::stdin()
+    .lock()
+    .read_line(&mut choice)
+    .map_err(|err| err.to_string())?;
+  choice
+    .trim()
+    .parse()
+    .map_err(|_| format!("{:?} is not a block number", choice.trim()))
+}
+
+fn extract_samples(
+  day: u8,
+  page: PathBuf,
+  block: Option<usize>,
+  output: Option<PathBuf>,
+) -> Result<(), String> {
+  let html =
+    fs::read_to_string(&page).map_err(|err| format!("cannot read {}: {err}", page.display()))?;
+  let blocks = samples::code_blocks(&html);
+  let block = match (block, blocks.len()) {
+    (_, 0) => return Err(format!("{} has no code blocks", page.display())),
+    (Some(block), _) => block,
+    (None, 1) => 1,
+    (None, _) => pick_block(&blocks)?,
+  };
+  let sample = blocks.get(block.wrapping_sub(1)).ok_or_else(|| {
+    format!(
+      "block {block} does not exist, the page has {}",
+      blocks.len()
+    )
+  })?;
+
+  let output = match output {
+    Some(output) => output,
+    None => samples::sample_path("src", day).ok_or_else(|| {
+      format!("day {day} keeps its sample inline in src/day{day}.rs, pick a file with --output")
+    })?,
+  };
+  if let Some(parent) = output.parent() {
+    fs::create_dir_all(parent)
+      .map_err(|err| format!("cannot create {}: {err}", parent.display()))?;
+  }
+  fs::write(&output, sample).map_err(|err| format!("cannot write {}: {err}", output.display()))?;
+  println!("Wrote block {block} to {}", output.display());
+
+  let candidates = samples::answer_candidates(&html);
+  if !candidates.is_empty() {
+    let path = samples::candidates_path(&output);
+    fs::write(&path, candidates.join("\n") + "\n")
+      .map_err(|err| format!("cannot write {}: {err}", path.display()))?;
+    println!(
+      "Wrote the answer candidates {} to {}",
+      candidates.join(", "),
+      path.display()
+    );
+  }
+  Ok(())
+}
+
+fn encrypt_inputs(config: &Config, days: Vec<u8>) -> Result<(), String> {
+  let vault = Vault::from_secret(&config.vault).map_err(|err| err.to_string())?;
+  if vault.is_locked() {
+    return Err("no vault key is set, generate one with `aoc vault keygen`".to_string());
+  }
+  let days = match days.is_empty() {
+    true => registry::DAYS.iter().collect::<Vec<_>>(),
+    false => days
+      .into_iter()
+      .map(|day| registry::get(day).ok_or_else(|| format!("day {day} does not exist")))
+      .collect::<Result<_, _>>()?,
+  };
+  for day in days {
+    let encrypted = vault
+      .encrypt(day.input_path(config))
+      .map_err(|err| err.to_string())?;
+    println!("Encrypted {}", encrypted.display());
+  }
+  Ok(())
+}
+
+fn check_matrix(config: &Config, dir: PathBuf, time_limit: Duration) -> Result<(), String> {
+  let vault = Vault::from_secret(&config.vault).map_err(|err| err.to_string())?;
+  let mut users = fs::read_dir(&dir)
+    .and_then(|entries| {
+      entries
+        .filter_map(|entry| {
+          entry
+            .map(|entry| entry.path().is_dir().then(|| entry.file_name()))
+            .transpose()
+        })
+        .collect::<io::Result<Vec<_>>>()
+    })
+    .map_err(|err| format!("cannot read {}: {err}", dir.display()))?
+    .into_iter()
+    .map(|user| user.to_string_lossy().into_owned())
+    .collect::<Vec<_>>();
+  users.sort();
+
+  let mut matrix = Matrix {
+    users,
+    days: vec![],
+  };
+  for day in &registry::DAYS {
+    let outcomes = matrix
+      .users
+      .iter()
+      .map(|user| {
+        let path = dir.join(user).join(format!("day_{}", day.day));
+        let input = match vault.read(&path) {
+          Ok(input) => input,
+          Err(VaultError::Missing(_)) => return day.parts().map(|_| Outcome::NoInput).collect(),
+          Err(err) => {
+            return day
+              .parts()
+              .map(|_| Outcome::Error(err.to_string()))
+              .collect()
+          }
+        };
+        let answers = match matrix::read_answers(matrix::answers_path(&path)) {
+          Ok(answers) => answers,
+          Err(err) => {
+            let err = format!("cannot read the answers: {err}");
+            return day.parts().map(|_| Outcome::Error(err.clone())).collect();
+          }
+        };
+        day
+          .parts()
+          .map(|(number, part, within)| {
+            let expected = answers[number as usize - 1].as_ref();
+            match within {
+              Some(within) => Outcome::check_best(
+                expected,
+                registry::catch(|| {
+                  let cancellation = Cancellation::after(time_limit);
+                  within(&input, &cancellation, &mut BoundStats::start())
+                }),
+              ),
+              None => Outcome::check(expected, registry::catch(|| part(&input, config))),
+            }
+          })
+          .collect::<Vec<_>>()
+      })
+      .collect::<Vec<_>>();
+    if outcomes
+      .iter()
+      .flatten()
+      .any(|outcome| *outcome != Outcome::NoInput)
+    {
+      matrix.days.push((day.day, outcomes));
+    }
+  }
+
+  print!("{matrix}");
+  let failures = matrix.failures().count();
+  for (user, day, part, outcome) in matrix.failures() {
+    println!("{user}, day {day} part {part}: {outcome}");
+  }
+  match failures {
+    0 => Ok(()),
+    _ => Err(format!("{failures} parts failed")),
+  }
+}
+
+fn show_status(config: &Config, no_tests: bool) -> Result<(), String> {
+  let samples = match no_tests {
+    true => HashMap::new(),
+    false => {
+      let output = process::Command::new("cargo")
+        .args(["test", "--lib", "--", "tests::test_solve_part_"])
+        .stderr(process::Stdio::inherit())
+        .output()
+        .map_err(|err| format!("cannot run the sample tests: {err}"))?;
+      status::sample_results(&String::from_utf8_lossy(&output.stdout))
+    }
+  };
+  let records =
+    history::load(history::PATH).map_err(|err| format!("cannot read {}: {err}", history::PATH))?;
+
+  let calendar = Calendar(
+    registry::DAYS
+      .iter()
+      .map(|day| {
+        let answers = matrix::read_answers(matrix::answers_path(day.input_path(config)))
+          .unwrap_or_default()
+          .map(|answer| answer.is_some());
+        let solvers = [day.part_1, day.part_2];
+        status::Day {
+          day: day.day,
+          title: day.title,
+          parts: [1, 2].map(|part| status::Part {
+            implemented: solvers[part as usize - 1].is_some(),
+            sample: samples.get(&(day.day, part)).copied(),
+            answer_stored: answers[part as usize - 1],
+            duration: records
+              .iter()
+              .rev()
+              .find(|record| {
+                record.day == day.day
+                  && record.part == part
+                  && record.profile == history::Profile::current()
+              })
+              .map(|record| record.duration),
+          }),
+        }
+      })
+      .collect(),
+  );
+  print!("{calendar}");
+  Ok(())
+}
+
+fn main() -> ExitCode {
+  let cli = Cli::parse();
+  let config = match Config::load(&cli.config) {
+    Ok(config) => config,
+    Err(err) => {
+      eprintln!("error: cannot load {}: {err}", cli.config.display());
+      return ExitCode::FAILURE;
+    }
+  };
+  let result = match cli.command {
+    Command::Run {
+      day,
+      explain,
+      time_limit,
+      implementation,
+      stats,
+    } => match time_limit.map(Duration::try_from_secs_f64).transpose() {
+      Ok(time_limit) => run(
+        &config,
+        day,
+        RunOptions {
+          explain,
+          time_limit,
+          implementation,
+          stats,
+        },
+      ),
+      Err(err) => Err(format!("invalid time limit: {err}")),
+    },
+    Command::Bench {
+      day,
+      runs,
+      scale: false,
+      ..
+    } => compare(&config, day, runs),
+    Command::Bench {
+      day,
+      runs,
+      scale: true,
+      steps,
+      plot,
+    } => measure_scaling(&config, day, runs, steps, plot),
+    Command::History { day } => show_history(day),
+    Command::Samples {
+      day,
+      page,
+      block,
+      output,
+    } => extract_samples(day, page, block, output),
+    Command::Repl { day, part } => repl::start(&config, day, part),
+    Command::Matrix { dir, time_limit } => match Duration::try_from_secs_f64(time_limit) {
+      Ok(time_limit) => check_matrix(&config, dir, time_limit),
+      Err(err) => Err(format!("invalid time limit: {err}")),
+    },
+    Command::Status { no_tests } => show_status(&config, no_tests),
+    Command::Vault(VaultCommand::Keygen) => {
+      println!("{}", vault::generate_key());
+      Ok(())
+    }
+    Command::Vault(VaultCommand::Encrypt { days }) => encrypt_inputs(&config, days),
+  };
+
+  match result {
+    Ok(()) => ExitCode::SUCCESS,
+    Err(err) => {
+      eprintln!("error: {err}");
+      ExitCode::FAILURE
+    }
+  }
+}
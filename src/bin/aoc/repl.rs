@@ -0,0 +1,225 @@
+//! `aoc repl`: solving inputs typed or pasted at a prompt.
+
+use std::io::{self, BufRead, Write};
+
+use aoc_2022::{
+  bench,
+  config::Config,
+  registry::{self, catch, Day},
+};
+
+const HELP: &str = "\
+Type or paste an input and finish it with a blank line to solve it.
+  :day N       switches to day N
+  :part N      only solves part N, 1 or 2
+  :part        solves every part again
+  :parse       pretty-prints how the next input is parsed instead of solving it
+  :begin       starts an input that has blank lines of its own, up to a line with :end
+  :help        shows this
+  :quit        leaves, as does end of input";
+
+pub struct Repl<'a> {
+  config: &'a Config,
+  day: Option<&'static Day>,
+  part: Option<u8>,
+  parse_next: bool,
+}
+
+impl<'a> Repl<'a> {
+  pub fn new(config: &'a Config, day: Option<u8>, part: Option<u8>) -> Result<Self, String> {
+    let mut repl = Self {
+      config,
+      day: None,
+      part: None,
+      parse_next: false,
+    };
+    if let Some(day) = day {
+      repl.select_day(day)?;
+    }
+    if let Some(part) = part {
+      repl.select_part(part)?;
+    }
+    Ok(repl)
+  }
+
+  /// Switches to `day`, giving its title.
+  fn select_day(&mut self, day: u8) -> Result<String, String> {
+    let day = registry::get(day).ok_or_else(|| format!("day {day} does not exist"))?;
+    self.day = Some(day);
+    Ok(format!("Day {}: {}", day.day, day.title))
+  }
+
+  fn select_part(&mut self, part: u8) -> Result<String, String> {
+    if !(1..=2).contains(&part) {
+      return Err(format!("part {part} does not exist"));
+    }
+    self.part = Some(part);
+    Ok(String::new())
+  }
+
+  /// Reads inputs and commands until the end of `lines`, writing what they give to `out` and
+  /// their errors to `err`.
+  pub fn run(
+    &mut self,
+    mut lines: impl Iterator<Item = io::Result<String>>,
+    out: &mut impl Write,
+    err: &mut impl Write,
+  ) -> io::Result<()> {
+    if let Some(day) = self.day {
+      writeln!(out, "Day {}: {}", day.day, day.title)?;
+    }
+    writeln!(out, "{HELP}")?;
+    loop {
+      write!(out, "aoc> ")?;
+      out.flush()?;
+      let Some(line) = lines.next().transpose()? else {
+        return Ok(());
+      };
+      let result = match line.trim() {
+        "" => Ok(String::new()),
+        ":quit" | ":q" => return Ok(()),
+        ":help" => Ok(HELP.to_string()),
+        ":parse" => {
+          self.parse_next = true;
+          Ok(String::new())
+        }
+        ":part" => {
+          self.part = None;
+          Ok(String::new())
+        }
+        ":begin" => {
+          let input = read_until(&mut lines, |line| line.trim() == ":end")?;
+          self.solve(&input)
+        }
+        command if command.starts_with(':') => self.command(command),
+        _ => {
+          let rest = read_until(&mut lines, |line| line.trim().is_empty())?;
+          self.solve([line, rest].join("\n").trim_end_matches('\n'))
+        }
+      };
+      match result {
+        Ok(output) if output.is_empty() => (),
+        Ok(output) => writeln!(out, "{output}")?,
+        Err(message) => writeln!(err, "error: {message}")?,
+      }
+    }
+  }
+
+  fn command(&mut self, command: &str) -> Result<String, String> {
+    let (name, argument) = command.split_once(' ').unwrap_or((command, ""));
+    let number = || {
+      argument
+        .trim()
+        .parse::<u8>()
+        .map_err(|_| format!("{name} takes a number, not {:?}", argument.trim()))
+    };
+    match name {
+      ":day" => self.select_day(number()?),
+      ":part" => self.select_part(number()?),
+      _ => Err(format!("unknown command {name}, see :help")),
+    }
+  }
+
+  /// The answers of the selected parts on `input`, or how it is parsed after `:parse`.
+  fn solve(&mut self, input: &str) -> Result<String, String> {
+    let day = self.day.ok_or("pick a day first with :day N")?;
+
+    if std::mem::take(&mut self.parse_next) {
+      let parse = day
+        .parse
+        .ok_or_else(|| format!("day {} has no parser to show", day.day))?;
+      return catch(|| parse(input));
+    }
+
+    let mut answers = vec![];
+    for (number, part, _) in day.parts() {
+      if self.part.is_some_and(|part| part != number) {
+        continue;
+      }
+      let (answer, measurement) = catch(|| bench::measure(|| part(input, self.config)))?;
+      answers.push(format!("Part {number}: {answer} ({measurement})"));
+    }
+    Ok(answers.join("\n"))
+  }
+}
+
+/// The lines up to the one `is_end` accepts, which is left out, or up to the end of `lines`.
+/// Like the puzzle inputs, the result does not end with a newline.
+fn read_until(
+  lines: &mut impl Iterator<Item = io::Result<String>>,
+  is_end: impl Fn(&str) -> bool,
+) -> io::Result<String> {
+  let mut input = vec![];
+  for line in lines {
+    let line = line?;
+    if is_end(&line) {
+      break;
+    }
+    input.push(line);
+  }
+  Ok(input.join("\n"))
+}
+
+/// Starts a session on standard input.
+pub fn start(config: &Config, day: Option<u8>, part: Option<u8>) -> Result<(), String> {
+  Repl::new(config, day, part)?
+    .run(
+      io::stdin().lock().lines(),
+      &mut io::stdout(),
+      &mut io::stderr(),
+    )
+    .map_err(|err| err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// What a session on `input` writes to its output and its errors, without the prompts.
+  fn session(day: u8, input: &str) -> (String, String) {
+    let config = Config::default();
+    let (mut out, mut err) = (vec![], vec![]);
+    Repl::new(&config, Some(day), None)
+      .unwrap()
+      .run(
+        input.lines().map(|line| Ok(line.to_string())),
+        &mut out,
+        &mut err,
+      )
+      .unwrap();
+    (
+      String::from_utf8(out).unwrap().replace("aoc> ", ""),
+      String::from_utf8(err).unwrap(),
+    )
+  }
+
+  #[test]
+  fn test_begin_end() {
+    let (out, err) = session(1, ":begin\n1000\n2000\n\n4000\n\n3000\n:end\n:quit");
+    assert_eq!(err, "");
+    assert!(out.contains("Part 1: 4000 ("));
+    assert!(out.contains("Part 2: 10000 ("));
+  }
+
+  #[test]
+  fn test_parse_without_parser() {
+    let (out, err) = session(6, ":parse\nmjqjpqmgbljsphdztnvjfqwrcgsmlb");
+    assert_eq!(err, "error: day 6 has no parser to show\n");
+    assert!(!out.contains("Part"));
+  }
+
+  #[test]
+  fn test_part() {
+    let (out, err) = session(1, ":part 2\n1000\n\n:part 1\n5\n\n:part\n7\n\n:part 3");
+    assert_eq!(err, "error: part 3 does not exist\n");
+    let answers = out
+      .lines()
+      .filter(|line| line.starts_with("Part"))
+      .map(|line| line.split(" (").next().unwrap())
+      .collect::<Vec<_>>();
+    assert_eq!(
+      answers,
+      ["Part 2: 1000", "Part 1: 5", "Part 1: 7", "Part 2: 7"]
+    );
+  }
+}
@@ -1,6 +1,13 @@
 #![allow(unused_variables)]
 
-const INPUT: &str = include_str!("input");
+use aoc_2022::{
+  bench::{self, CountingAllocator},
+  config::{self, Config},
+  vault,
+};
+
+/// The day this is, set when copying the scaffold.
+const DAY: u8 = 0;
 
 pub(crate) fn part_1(input: &str) -> u32 {
   todo!()
@@ -10,9 +17,14 @@ pub(crate) fn part_2(input: &str) -> u32 {
   todo!()
 }
 
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
 fn main() {
-  println!("Part 1: {}", part_1(&INPUT));
-  println!("Part 2: {}", part_2(&INPUT));
+  let config = Config::load(config::PATH).unwrap();
+  let input = vault::read_input(&config, DAY, "src/bin/scaffold/input").unwrap();
+  bench::run("Part 1", || part_1(&input));
+  bench::run("Part 2", || part_2(&input));
 }
 
 #[cfg(test)]
@@ -0,0 +1,149 @@
+//! Stopping long searches early while keeping the best answer found so far.
+
+use std::{
+  fmt::Display,
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+  },
+  time::{Duration, Instant},
+};
+
+/// Tells a search to stop, either once a deadline passes or when [`cancel`](Self::cancel) is
+/// called from another thread. Clones share the same state.
+#[derive(Debug, Clone, Default)]
+pub struct Cancellation {
+  cancelled: Arc<AtomicBool>,
+  deadline: Option<Instant>,
+  /// The cancellation this one is a [`share`](Self::share) of.
+  parent: Option<Arc<AtomicBool>>,
+}
+
+impl Cancellation {
+  /// Only fires when cancelled by hand.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Fires once `timeout` has passed from now.
+  pub fn after(timeout: Duration) -> Self {
+    Self {
+      deadline: Some(Instant::now() + timeout),
+      ..Self::default()
+    }
+  }
+
+  /// A cancellation for one of `searches` searches run one after the other, firing once it
+  /// used an even share of the time left, or when this one is cancelled. Time a search leaves
+  /// unused goes to the following ones.
+  pub fn share(&self, searches: usize) -> Self {
+    let now = Instant::now();
+    Self {
+      cancelled: Arc::default(),
+      deadline: self
+        .deadline
+        .map(|deadline| now + deadline.saturating_duration_since(now) / searches.max(1) as u32),
+      parent: Some(self.cancelled.clone()),
+    }
+  }
+
+  pub fn cancel(&self) {
+    self.cancelled.store(true, Ordering::Relaxed)
+  }
+
+  /// Whether the search should stop. Cheap enough to be checked at every node.
+  pub fn is_cancelled(&self) -> bool {
+    if self.cancelled.load(Ordering::Relaxed)
+      || self
+        .parent
+        .as_ref()
+        .is_some_and(|parent| parent.load(Ordering::Relaxed))
+    {
+      return true;
+    }
+    match self.deadline {
+      Some(deadline) if Instant::now() >= deadline => {
+        self.cancel();
+        true
+      }
+      _ => false,
+    }
+  }
+}
+
+/// The best answer of a search, and whether the search ran to completion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Best<T> {
+  pub value: T,
+  /// `false` if the search was cancelled before it could rule out a better answer.
+  pub optimal: bool,
+}
+
+impl<T> Best<T> {
+  pub fn optimal(value: T) -> Self {
+    Self {
+      value,
+      optimal: true,
+    }
+  }
+
+  pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Best<U> {
+    Best {
+      value: f(self.value),
+      optimal: self.optimal,
+    }
+  }
+}
+
+impl<T: Display> Display for Best<T> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self.optimal {
+      true => write!(f, "{}", self.value),
+      false => write!(f, "{} (not proven optimal)", self.value),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_cancellation() {
+    let cancellation = Cancellation::new();
+    let clone = cancellation.clone();
+    assert!(!cancellation.is_cancelled());
+    clone.cancel();
+    assert!(cancellation.is_cancelled());
+
+    assert!(Cancellation::after(Duration::ZERO).is_cancelled());
+    assert!(!Cancellation::after(Duration::from_secs(60)).is_cancelled());
+  }
+
+  #[test]
+  fn test_share() {
+    let cancellation = Cancellation::after(Duration::from_secs(60));
+    let share = cancellation.share(3);
+    let left = share.deadline.unwrap() - Instant::now();
+    assert!(left <= Duration::from_secs(20) && left > Duration::from_secs(19));
+    assert!(!share.is_cancelled());
+    cancellation.cancel();
+    assert!(share.is_cancelled());
+
+    assert_eq!(Cancellation::new().share(3).deadline, None);
+    assert!(Cancellation::after(Duration::ZERO).share(1).is_cancelled());
+  }
+
+  #[test]
+  fn test_display() {
+    assert_eq!(Best::optimal(42).to_string(), "42");
+    assert_eq!(
+      Best {
+        value: 42,
+        optimal: false
+      }
+      .to_string(),
+      "42 (not proven optimal)"
+    );
+  }
+}
@@ -0,0 +1,135 @@
+//! Arithmetic for solvers working on narrow integers.
+//!
+//! With the `checked` feature an overflow panics with an [`Overflow`] naming the day and the
+//! operation, instead of wrapping silently in release builds. Without it these are the plain
+//! operators.
+
+use std::{
+  any,
+  error::Error,
+  fmt::Display,
+  ops::{Add, Mul, Sub},
+};
+
+use num::{CheckedAdd, CheckedMul, CheckedSub, Zero};
+
+/// An integer the helpers work on.
+pub trait Int:
+  Copy
+  + Display
+  + Zero
+  + Add<Output = Self>
+  + Sub<Output = Self>
+  + Mul<Output = Self>
+  + CheckedAdd
+  + CheckedSub
+  + CheckedMul
+{
+}
+
+impl<T> Int for T where
+  T: Copy
+    + Display
+    + Zero
+    + Add<Output = T>
+    + Sub<Output = T>
+    + Mul<Output = T>
+    + CheckedAdd
+    + CheckedSub
+    + CheckedMul
+{
+}
+
+/// An operation of a day's solver whose result does not fit its type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overflow {
+  pub day: u8,
+  /// The operation with its operands and type, such as `65535 + 1 in u16`.
+  pub operation: String,
+}
+
+impl Display for Overflow {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "day {}: {} overflows", self.day, self.operation)
+  }
+}
+
+impl Error for Overflow {}
+
+#[track_caller]
+#[cfg_attr(not(feature = "checked"), allow(dead_code))]
+fn check<T: Int>(day: u8, result: Option<T>, a: T, operator: char, b: T) -> T {
+  match result {
+    Some(result) => result,
+    None => panic!(
+      "{}",
+      Overflow {
+        day,
+        operation: format!("{a} {operator} {b} in {}", any::type_name::<T>()),
+      }
+    ),
+  }
+}
+
+/// `a + b`, see the [module documentation](self).
+#[inline]
+#[track_caller]
+#[cfg_attr(not(feature = "checked"), allow(unused_variables))]
+pub fn add<T: Int>(day: u8, a: T, b: T) -> T {
+  #[cfg(feature = "checked")]
+  return check(day, a.checked_add(&b), a, '+', b);
+  #[cfg(not(feature = "checked"))]
+  return a + b;
+}
+
+/// `a - b`, see the [module documentation](self).
+#[inline]
+#[track_caller]
+#[cfg_attr(not(feature = "checked"), allow(unused_variables))]
+pub fn sub<T: Int>(day: u8, a: T, b: T) -> T {
+  #[cfg(feature = "checked")]
+  return check(day, a.checked_sub(&b), a, '-', b);
+  #[cfg(not(feature = "checked"))]
+  return a - b;
+}
+
+/// `a * b`, see the [module documentation](self).
+#[inline]
+#[track_caller]
+#[cfg_attr(not(feature = "checked"), allow(unused_variables))]
+pub fn mul<T: Int>(day: u8, a: T, b: T) -> T {
+  #[cfg(feature = "checked")]
+  return check(day, a.checked_mul(&b), a, '*', b);
+  #[cfg(not(feature = "checked"))]
+  return a * b;
+}
+
+/// Sum of all `values`, adding them one by one with [`add`].
+#[track_caller]
+pub fn sum<T: Int>(day: u8, values: impl IntoIterator<Item = T>) -> T {
+  let mut total = T::zero();
+  for value in values {
+    total = add(day, total, value);
+  }
+  total
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_operations() {
+    assert_eq!(add(1, 200u8, 55), 255);
+    assert_eq!(sub(1, 7u16, 7), 0);
+    assert_eq!(mul(11, 3u64, 5), 15);
+    assert_eq!(sum(1, [1u32, 2, 3]), 6);
+  }
+
+  #[test]
+  #[cfg(feature = "checked")]
+  #[should_panic(expected = "day 16: 65535 + 1 in u16 overflows")]
+  fn test_overflow() {
+    add(16, u16::MAX, 1);
+  }
+}
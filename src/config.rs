@@ -0,0 +1,274 @@
+//! Settings read from `aoc.toml`, every one of them optional.
+//!
+//! ```toml
+//! [inputs]
+//! dir = "inputs"          # reads `inputs/day_N` instead of each day's own input
+//!
+//! [session]
+//! env = "AOC_SESSION"     # or `file = "/path/to/session"`
+//!
+//! [vault]
+//! file = "~/.config/aoc/vault_key"
+//!
+//! [output]
+//! format = "json"
+//!
+//! [days.15]
+//! row = 10
+//! bound = 20
+//! ```
+
+use std::{
+  env,
+  fmt::Display,
+  fs, io,
+  path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::{day15, day17, day20, day22};
+
+/// Where the runner looks for its configuration, relative to the crate root.
+pub const PATH: &str = "aoc.toml";
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+  pub inputs: Inputs,
+  /// The adventofcode.com session token, from `AOC_SESSION` unless set.
+  pub session: Secret,
+  /// The key of the encrypted inputs, from `AOC_VAULT_KEY` unless set. See [`crate::vault`].
+  pub vault: Secret,
+  pub output: Output,
+  pub days: Days,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Inputs {
+  /// Directory holding a `day_N` file per day. Unset, every day reads its usual input.
+  pub dir: Option<PathBuf>,
+}
+
+/// Where a value kept out of the repository comes from.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Secret {
+  /// Environment variable holding the value.
+  pub env: Option<String>,
+  /// File holding the value, taking precedence over the environment variable. A leading `~`
+  /// stands for the home directory.
+  pub file: Option<PathBuf>,
+}
+
+impl Secret {
+  pub fn session() -> Self {
+    Self::from_env("AOC_SESSION")
+  }
+
+  pub fn vault() -> Self {
+    Self::from_env("AOC_VAULT_KEY")
+  }
+
+  fn from_env(env: &str) -> Self {
+    Self {
+      env: Some(env.to_string()),
+      file: None,
+    }
+  }
+
+  /// The value, if its source is set. A file that is set but cannot be read is an error
+  /// rather than a missing value, so that a wrong path does not go unnoticed.
+  pub fn value(&self) -> Result<Option<String>, SecretError> {
+    let value = match (&self.file, &self.env) {
+      (Some(file), _) => {
+        let file = expand_home(file);
+        fs::read_to_string(&file).map_err(|err| SecretError { path: file, err })?
+      }
+      (None, Some(env)) => match env::var(env) {
+        Ok(value) => value,
+        Err(_) => return Ok(None),
+      },
+      (None, None) => return Ok(None),
+    };
+    Ok(Some(value.trim().to_string()).filter(|value| !value.is_empty()))
+  }
+}
+
+/// `path` with a leading `~` replaced by the home directory, if `HOME` is set.
+fn expand_home(path: &Path) -> PathBuf {
+  match (path.strip_prefix("~"), env::var_os("HOME")) {
+    (Ok(rest), Some(home)) => PathBuf::from(home).join(rest),
+    _ => path.to_path_buf(),
+  }
+}
+
+/// The file of a [`Secret`] could not be read.
+#[derive(Debug)]
+pub struct SecretError {
+  pub path: PathBuf,
+  pub err: io::Error,
+}
+
+impl Display for SecretError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "cannot read {}: {}", self.path.display(), self.err)
+  }
+}
+
+impl std::error::Error for SecretError {}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Output {
+  pub format: Format,
+}
+
+/// How the runner prints answers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+  /// One line per part, with its measurement.
+  #[default]
+  Text,
+  /// One JSON object per part.
+  Json,
+}
+
+/// The named parameters of the days that have some, under `[days.N]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Days {
+  #[serde(rename = "15")]
+  pub day15: day15::Params,
+  #[serde(rename = "17")]
+  pub day17: day17::Params,
+  #[serde(rename = "20")]
+  pub day20: day20::Params,
+  #[serde(rename = "22")]
+  pub day22: day22::Params,
+}
+
+/// Why the configuration could not be loaded.
+#[derive(Debug)]
+pub enum ConfigError {
+  Io(io::Error),
+  Toml(toml::de::Error),
+}
+
+impl Display for ConfigError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      ConfigError::Io(err) => write!(f, "{err}"),
+      ConfigError::Toml(err) => write!(f, "{}", err.message()),
+    }
+  }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl Default for Config {
+  fn default() -> Self {
+    Self {
+      inputs: Inputs::default(),
+      session: Secret::session(),
+      vault: Secret::vault(),
+      output: Output::default(),
+      days: Days::default(),
+    }
+  }
+}
+
+impl Config {
+  /// Reads the configuration at `path`. A missing file leaves everything at its default.
+  pub fn load(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+    match fs::read_to_string(path) {
+      Ok(contents) => contents.parse(),
+      Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+      Err(err) => Err(ConfigError::Io(err)),
+    }
+  }
+
+  /// Where the input of `day` lives, given `default` as its usual path.
+  pub fn input_path(&self, day: u8, default: &str) -> PathBuf {
+    match &self.inputs.dir {
+      Some(dir) => dir.join(format!("day_{day}")),
+      None => PathBuf::from(default),
+    }
+  }
+}
+
+impl std::str::FromStr for Config {
+  type Err = ConfigError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    toml::from_str(s).map_err(ConfigError::Toml)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_defaults() {
+    let config = "[days.15]\nrow = 10\n".parse::<Config>().unwrap();
+
+    assert_eq!(config.days.day15.row, 10);
+    assert_eq!(config.days.day15.bound, 4_000_000);
+    assert_eq!(config.days.day22.face_size, 50);
+    assert_eq!(config.output.format, Format::Text);
+    assert_eq!(config.vault.env.as_deref(), Some("AOC_VAULT_KEY"));
+    assert_eq!(
+      config.input_path(15, "assets/day_15"),
+      Path::new("assets/day_15")
+    );
+  }
+
+  #[test]
+  fn test_settings() {
+    let config = r#"
+      [inputs]
+      dir = "inputs"
+
+      [output]
+      format = "json"
+
+      [days.20]
+      decryption_key = 1
+    "#
+    .parse::<Config>()
+    .unwrap();
+
+    assert_eq!(
+      config.input_path(3, "assets/day_3"),
+      Path::new("inputs/day_3")
+    );
+    assert_eq!(config.output.format, Format::Json);
+    assert_eq!(config.days.day20.decryption_key, 1);
+    assert!("[days.15]\nrows = 10\n".parse::<Config>().is_err());
+  }
+
+  #[test]
+  fn test_secret() {
+    let missing = Secret {
+      env: None,
+      file: Some(PathBuf::from("/nonexistent/vault_key")),
+    };
+
+    let err = missing.value().unwrap_err();
+    assert_eq!(err.path, Path::new("/nonexistent/vault_key"));
+    assert!(err
+      .to_string()
+      .starts_with("cannot read /nonexistent/vault_key: "));
+    assert_eq!(Secret::default().value().unwrap(), None);
+    if let Some(home) = env::var_os("HOME") {
+      assert_eq!(
+        expand_home(Path::new("~/.config/aoc/vault_key")),
+        PathBuf::from(home).join(".config/aoc/vault_key")
+      );
+    }
+    assert_eq!(expand_home(Path::new("/etc/key")), Path::new("/etc/key"));
+  }
+}
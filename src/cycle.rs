@@ -0,0 +1,182 @@
+//! Finding where a simulation starts repeating itself, to jump ahead to far-away steps.
+
+use std::{collections::HashMap, fmt::Display, hash::Hash};
+
+use num::PrimInt;
+
+/// The steps at which a simulation repeats: the state after `start + length` steps is the
+/// same as the one after `start` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+  pub start: usize,
+  pub length: usize,
+}
+
+/// A metric recorded after every step of a simulation, and its cycle if one was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timeline<M> {
+  /// The metric after each step, `metrics[0]` being the initial state.
+  pub metrics: Vec<M>,
+  pub cycle: Option<Cycle>,
+}
+
+/// The simulation did not repeat within the steps it was allowed, short of the steps asked
+/// for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoCycle {
+  pub limit: usize,
+}
+
+impl Display for NoCycle {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "no cycle within {} steps", self.limit)
+  }
+}
+
+impl std::error::Error for NoCycle {}
+
+/// Steps the simulation until the state key repeats or `max_steps` steps have been made,
+/// recording `metric` along the way. Gives up after `limit` steps without a cycle, so that
+/// a far-away `max_steps` cannot run forever.
+///
+/// The key has to capture everything the following steps depend on, otherwise a cycle is
+/// reported where there is none. A state whose key is `None` could not be captured: the
+/// states before it are forgotten, so that no cycle spans it.
+pub fn simulate<S, K: Hash + Eq, M>(
+  mut state: S,
+  mut key: impl FnMut(&S) -> Option<K>,
+  mut metric: impl FnMut(&S) -> M,
+  mut step: impl FnMut(&mut S),
+  max_steps: usize,
+  limit: usize,
+) -> Result<Timeline<M>, NoCycle> {
+  let mut seen = HashMap::new();
+  let mut metrics = vec![];
+
+  for steps in 0..=max_steps {
+    metrics.push(metric(&state));
+    match key(&state) {
+      Some(key) => {
+        if let Some(start) = seen.insert(key, steps) {
+          return Ok(Timeline {
+            metrics,
+            cycle: Some(Cycle {
+              start,
+              length: steps - start,
+            }),
+          });
+        }
+      }
+      None => seen.clear(),
+    }
+    if steps == max_steps {
+      break;
+    }
+    if steps == limit {
+      return Err(NoCycle { limit });
+    }
+    step(&mut state);
+  }
+
+  Ok(Timeline {
+    metrics,
+    cycle: None,
+  })
+}
+
+impl<M: PrimInt> Timeline<M> {
+  /// The metric after `steps` steps, assuming it changes by the same amount in every cycle.
+  ///
+  /// `None` if that step was neither simulated nor covered by a cycle.
+  pub fn at(&self, steps: usize) -> Option<M> {
+    if let Some(&metric) = self.metrics.get(steps) {
+      return Some(metric);
+    }
+    let Cycle { start, length } = self.cycle?;
+    let (cycles, offset) = ((steps - start) / length, (steps - start) % length);
+    let per_cycle = self.metrics[start + length] - self.metrics[start];
+
+    Some(self.metrics[start + offset] + per_cycle * M::from(cycles)?)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_cycle() {
+    // 3 steps of warm-up, then a cycle of 4 steps adding 10 each time around.
+    let timeline = simulate(
+      (0u64, 0u64),
+      |&(step, _)| Some(if step < 3 { step } else { 3 + (step - 3) % 4 }),
+      |&(_, total)| total,
+      |(step, total)| {
+        *step += 1;
+        *total += if *step > 3 && (*step - 3) % 4 == 0 {
+          7
+        } else {
+          1
+        };
+      },
+      1000,
+      1000,
+    )
+    .unwrap();
+
+    assert_eq!(
+      timeline.cycle,
+      Some(Cycle {
+        start: 3,
+        length: 4
+      })
+    );
+    assert_eq!(timeline.at(5), Some(5));
+    assert_eq!(
+      timeline.at(3 + 4 * 1_000_000_000),
+      Some(3 + 10 * 1_000_000_000)
+    );
+    assert_eq!(
+      timeline.at(3 + 4 * 1_000_000_000 + 2),
+      Some(5 + 10 * 1_000_000_000)
+    );
+  }
+
+  #[test]
+  fn test_no_cycle() {
+    let timeline = simulate(0u32, |&n| Some(n), |&n| n * n, |n| *n += 1, 10, 10).unwrap();
+
+    assert_eq!(timeline.cycle, None);
+    assert_eq!(timeline.at(10), Some(100));
+    assert_eq!(timeline.at(11), None);
+    assert_eq!(
+      simulate(
+        0u64,
+        |&n| Some(n),
+        |&n| n,
+        |n| *n += 1,
+        1_000_000_000_000,
+        100
+      ),
+      Err(NoCycle { limit: 100 })
+    );
+    // Every state repeats the one 2 steps before, but an uncapturable state every 3 steps
+    // keeps the repeats from counting until the key stops being `None`.
+    assert_eq!(
+      simulate(
+        0u32,
+        |&n| (n % 3 != 2 || n > 8).then_some(n % 2),
+        |&n| n,
+        |n| *n += 1,
+        1000,
+        1000,
+      )
+      .unwrap()
+      .cycle,
+      Some(Cycle {
+        start: 9,
+        length: 2
+      })
+    );
+  }
+}
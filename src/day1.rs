@@ -0,0 +1,61 @@
+//! Day 1: Calorie Counting
+
+use crate::{
+  checked,
+  parsers::{blocks, lines, parse_all, unsigned},
+};
+
+const DAY: u8 = 1;
+
+/// Total calories carried by each elf, in input order.
+pub fn parse(input: &str) -> impl Iterator<Item = u32> {
+  parse_all(blocks(lines(unsigned::<u32>)), input)
+    .unwrap()
+    .into_iter()
+    .map(|inventory| checked::sum(DAY, inventory))
+}
+
+/// Calories carried by the elf carrying the most.
+pub fn part_1(input: &str) -> u32 {
+  parse(input).max().unwrap()
+}
+
+/// Calories carried by the three elves carrying the most.
+pub fn part_2(input: &str) -> u32 {
+  let mut inventories = parse(input).collect::<Vec<_>>();
+
+  inventories.sort_by(|a, b| b.cmp(a));
+  checked::sum(DAY, inventories.into_iter().take(3))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_INPUT: &str = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+  #[test]
+  fn test_solve_part_1() {
+    let res = part_1(TEST_INPUT);
+    assert_eq!(res, 24000);
+  }
+
+  #[test]
+  fn test_solve_part_2() {
+    let res = part_2(TEST_INPUT);
+    assert_eq!(res, 45000)
+  }
+}
@@ -0,0 +1,345 @@
+//! Day 11: Monkey in the Middle
+
+use std::{cell::RefCell, fmt::Debug, ops::Range, rc::Rc};
+
+use nom::{
+  branch::alt,
+  bytes::complete::tag,
+  character::complete::{self, line_ending, multispace0},
+  combinator::{map, opt},
+  multi::separated_list1,
+  sequence::{delimited, preceded, separated_pair},
+  IResult,
+};
+
+use crate::{checked, explain::Trace, math::lcm};
+
+const DAY: u8 = 11;
+
+/// The monkeys passing items around, in the order they take their turns.
+#[derive(Debug)]
+pub struct KeepAway<O, T>
+where
+  O: Fn(u64) -> u64,
+  T: Fn(u64) -> bool,
+{
+  players: Vec<Rc<RefCell<Monkey<O, T>>>>,
+}
+
+impl<O, T> KeepAway<O, T>
+where
+  O: Fn(u64) -> u64,
+  T: Fn(u64) -> bool,
+{
+  pub fn new(players: Vec<Monkey<O, T>>) -> Self {
+    KeepAway {
+      players: players
+        .into_iter()
+        .map(|player| Rc::new(RefCell::new(player)))
+        .collect(),
+    }
+  }
+
+  /// Number of items each monkey has inspected so far.
+  pub fn inspections(&self) -> Vec<usize> {
+    self
+      .players
+      .iter()
+      .map(|monkey| monkey.borrow().inspections)
+      .collect()
+  }
+
+  /// Plays the given rounds. Worry levels are divided by three after every inspection, or
+  /// reduced modulo `common_divisible` if it is given.
+  pub fn play(&mut self, rounds: Range<usize>, common_divisible: Option<u64>) {
+    for _ in rounds {
+      for monkey in 0..self.players.len() {
+        let monkey = self.players[monkey].clone();
+        let items = monkey
+          .borrow_mut()
+          .starting_items
+          .drain(..)
+          .collect::<Vec<u64>>();
+        for item in items {
+          monkey.borrow_mut().inspections += 1;
+          let new_worry_level = (monkey.borrow().operation)(item);
+          let reliefed_worry_level = match common_divisible {
+            Some(common_divisible) => new_worry_level % common_divisible,
+            None => new_worry_level / 3,
+          };
+
+          let receiver = match (monkey.borrow().test)(reliefed_worry_level) {
+            true => monkey.borrow().throw_to.0,
+            false => monkey.borrow().throw_to.1,
+          };
+
+          self
+            .players
+            .get_mut(receiver)
+            .unwrap()
+            .borrow_mut()
+            .starting_items
+            .push(reliefed_worry_level);
+        }
+      }
+    }
+  }
+}
+
+/// A monkey with the items it currently holds and the rules it follows.
+pub struct Monkey<O, T>
+where
+  O: Fn(u64) -> u64,
+  T: Fn(u64) -> bool,
+{
+  pub starting_items: Vec<u64>,
+  /// Computes the new worry level of an inspected item.
+  pub operation: O,
+  /// Decides which of the two monkeys in `throw_to` receives the item.
+  pub test: T,
+  pub throw_to: (usize, usize),
+  pub inspections: usize,
+  /// The divisor used by `test`.
+  pub divisible: u64,
+}
+
+impl<O, T> Debug for Monkey<O, T>
+where
+  O: Fn(u64) -> u64,
+  T: Fn(u64) -> bool,
+{
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("Monkey")
+      .field("starting_items", &self.starting_items)
+      .field("throw_to", &self.throw_to)
+      .field("inspections", &self.inspections)
+      .finish()
+  }
+}
+
+enum Value {
+  Old,
+  Num(u64),
+}
+
+impl Value {
+  fn as_number(&self, old: u64) -> u64 {
+    match self {
+      Value::Old => old,
+      Value::Num(val) => *val,
+    }
+  }
+}
+
+fn parse_value(input: &str) -> IResult<&str, Value> {
+  alt((
+    map(tag("old"), |_| Value::Old),
+    map(complete::u64, Value::Num),
+  ))(input)
+}
+
+fn parse_function(input: &str) -> IResult<&str, impl Fn(u64) -> u64> {
+  let (input, (operand, value)) = preceded(
+    tag("new = old "),
+    separated_pair(
+      alt((complete::char('*'), complete::char('+'))),
+      multispace0,
+      parse_value,
+    ),
+  )(input)?;
+
+  Ok((input, move |old| match operand {
+    '*' => checked::mul(DAY, old, value.as_number(old)),
+    '+' => checked::add(DAY, old, value.as_number(old)),
+    _ => unreachable!(),
+  }))
+}
+
+fn parse_operation(input: &str) -> IResult<&str, impl Fn(u64) -> u64> {
+  delimited(
+    multispace0,
+    preceded(tag("Operation: "), parse_function),
+    line_ending,
+  )(input)
+}
+
+fn parse_starting_items(input: &str) -> IResult<&str, Vec<u64>> {
+  delimited(
+    multispace0,
+    preceded(
+      tag("Starting items: "),
+      separated_list1(tag(", "), complete::u64),
+    ),
+    line_ending,
+  )(input)
+}
+
+fn parse_test(input: &str) -> IResult<&str, (u64, impl Fn(u64) -> bool)> {
+  let (input, test_value) = delimited(
+    multispace0,
+    preceded(tag("Test: divisible by "), complete::u64),
+    line_ending,
+  )(input)?;
+
+  Ok((
+    input,
+    (test_value, move |worry_level| worry_level % test_value == 0),
+  ))
+}
+
+fn parse_throw_to(input: &str) -> IResult<&str, (usize, usize)> {
+  let (input, to_true) = delimited(
+    multispace0,
+    preceded(tag("If true: throw to monkey "), complete::u64),
+    line_ending,
+  )(input)?;
+  let (input, to_false) = delimited(
+    multispace0,
+    preceded(tag("If false: throw to monkey "), complete::u64),
+    opt(line_ending),
+  )(input)?;
+
+  Ok((input, (to_true as usize, to_false as usize)))
+}
+
+/// Parses a single monkey's block of notes.
+#[allow(clippy::type_complexity)]
+pub fn parse_monkey(
+  input: &str,
+) -> IResult<&str, Monkey<impl Fn(u64) -> u64, impl Fn(u64) -> bool>> {
+  let (input, _) = preceded(
+    delimited(tag("Monkey "), complete::u64, tag(":")),
+    line_ending,
+  )(input)?;
+  let (input, starting_items) = parse_starting_items(input)?;
+  let (input, operation) = parse_operation(input)?;
+  let (input, (test_value, test)) = parse_test(input)?;
+  let (input, throw_to) = parse_throw_to(input)?;
+
+  Ok((
+    input,
+    Monkey {
+      starting_items,
+      operation,
+      test,
+      throw_to,
+      inspections: 0,
+      divisible: test_value,
+    },
+  ))
+}
+
+/// Parses the notes of every monkey.
+pub fn parse(input: &str) -> Vec<Monkey<impl Fn(u64) -> u64, impl Fn(u64) -> bool>> {
+  input
+    .split("\n\n")
+    .map(|line| parse_monkey(line).unwrap().1)
+    .collect()
+}
+
+/// Level of monkey business after 20 rounds.
+pub fn part_1(input: &str) -> usize {
+  play(input, 20, true).0
+}
+
+/// Level of monkey business after 10000 rounds without relief.
+pub fn part_2(input: &str) -> usize {
+  play(input, 10_000, false).0
+}
+
+/// Level of monkey business after `rounds` rounds, with the trace of how many items every
+/// monkey inspected. Without relief, worry levels are kept modulo the common multiple of the
+/// monkeys' tests.
+fn play(input: &str, rounds: usize, relief: bool) -> (usize, Vec<Trace>) {
+  let monkeys = parse(input);
+  let common_divisible = (!relief).then(|| lcm(monkeys.iter().map(|monkey| monkey.divisible)));
+  let mut game = KeepAway::new(monkeys);
+
+  game.play(0..rounds, common_divisible);
+
+  let inspections = game.inspections();
+  let mut trace = inspections
+    .iter()
+    .enumerate()
+    .map(|(idx, inspections)| Trace::leaf(format!("Monkey {idx}"), inspections))
+    .collect::<Vec<_>>();
+
+  let mut inspections = inspections;
+  inspections.sort();
+  let monkey_business = inspections.iter().rev().take(2).product();
+
+  trace.push(Trace::leaf("monkey business", monkey_business));
+  (monkey_business, trace)
+}
+
+/// How many items every monkey inspected in each part.
+pub fn explain(input: &str) -> Trace {
+  Trace::new("Day 11").with_children([
+    Trace::new("Part 1").with_children(play(input, 20, true).1),
+    Trace::new("Part 2").with_children(play(input, 10_000, false).1),
+  ])
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_INPUT: &str = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1";
+
+  #[test]
+  fn test_solve_part_1() {
+    let res = part_1(TEST_INPUT);
+    assert_eq!(res, 10605);
+  }
+
+  #[test]
+  fn test_solve_part_2() {
+    let res = part_2(TEST_INPUT);
+    assert_eq!(res, 2713310158)
+  }
+
+  #[test]
+  fn test_explain() {
+    let trace = explain(TEST_INPUT);
+    assert_eq!(
+      trace.children[0].children,
+      [
+        Trace::leaf("Monkey 0", 101),
+        Trace::leaf("Monkey 1", 95),
+        Trace::leaf("Monkey 2", 7),
+        Trace::leaf("Monkey 3", 105),
+        Trace::leaf("monkey business", 10605),
+      ]
+    );
+    assert_eq!(
+      trace.children[1].children[4],
+      Trace::leaf("monkey business", 2713310158u64)
+    );
+  }
+}
@@ -0,0 +1,237 @@
+//! Day 12: Hill Climbing Algorithm
+
+use crate::{
+  scaling::Rng,
+  search::{astar, bfs, multi_source_bfs, SearchSpace},
+};
+
+/// A square of the heightmap as `(x, y)`.
+pub type Position = (usize, usize);
+
+/// Neighbouring squares that can be climbed to from `(x, y)`, each costing one step.
+pub fn successors((x, y): Position, grid: &[Vec<u8>]) -> Vec<(Position, u32)> {
+  let rows = grid.len();
+  let cols = grid[0].len();
+  let current_elevation = grid[y][x];
+  let mut successors = vec![];
+
+  if y > 0 {
+    successors.push((x, y - 1))
+  }
+
+  if y < rows - 1 {
+    successors.push((x, y + 1));
+  }
+
+  if x > 0 {
+    successors.push((x - 1, y));
+  }
+
+  if x < cols - 1 {
+    successors.push((x + 1, y));
+  }
+
+  successors
+    .iter()
+    .filter(|(x, y)| {
+      let elevation = grid[*y][*x];
+      elevation <= current_elevation + 1
+    })
+    .map(|successor| (*successor, 1u32))
+    .collect::<Vec<_>>()
+}
+
+/// Manhattan distance between two squares.
+pub fn distance((x, y): &Position, end: &Position) -> u32 {
+  (x.abs_diff(end.0) + y.abs_diff(end.1)) as u32
+}
+
+/// The start, the end and the elevation of every square, `a` being 0.
+pub fn parse(input: &str) -> (Position, Position, Vec<Vec<u8>>) {
+  let cols = input.lines().next().unwrap().len();
+  let rows = input.lines().count();
+  let mut start = (0, 0);
+  let mut end = (0, 0);
+  let mut grid = vec![vec![0; cols]; rows];
+
+  for (row, line) in input.lines().enumerate() {
+    for (col, c) in line.chars().enumerate() {
+      let elevation = match c {
+        'S' => {
+          start = (col, row);
+          'a'
+        }
+        'E' => {
+          end = (col, row);
+          'z'
+        }
+        'a'..='z' => c,
+        _ => unreachable!(),
+      };
+
+      let elevation = elevation as u8 - b'a';
+      grid[row][col] = elevation;
+    }
+  }
+
+  (start, end, grid)
+}
+
+/// Every square at the lowest elevation.
+pub fn possible_starts(grid: &[Vec<u8>]) -> Vec<Position> {
+  grid
+    .iter()
+    .enumerate()
+    .fold(vec![], |mut possible_starts, (row_idx, row)| {
+      row.iter().enumerate().for_each(|(col_idx, &item)| {
+        if item == 0 {
+          possible_starts.push((col_idx, row_idx))
+        }
+      });
+      possible_starts
+    })
+}
+
+/// The heightmap, climbed towards `end`.
+pub struct Climb<'a> {
+  pub grid: &'a [Vec<u8>],
+  pub end: Position,
+}
+
+impl SearchSpace for Climb<'_> {
+  type State = Position;
+
+  fn successors(&self, position: &Position) -> Vec<Position> {
+    successors(*position, self.grid)
+      .into_iter()
+      .map(|(successor, _)| successor)
+      .collect()
+  }
+
+  fn heuristic(&self, position: &Position) -> u64 {
+    distance(position, &self.end) as u64
+  }
+
+  fn is_goal(&self, position: &Position) -> bool {
+    *position == self.end
+  }
+}
+
+/// The heightmap, walked back down from the end to the nearest square at elevation `a`.
+pub struct Descent<'a> {
+  pub grid: &'a [Vec<u8>],
+}
+
+impl SearchSpace for Descent<'_> {
+  type State = Position;
+
+  fn successors(&self, &(x, y): &Position) -> Vec<Position> {
+    let elevation = self.grid[y][x];
+    [
+      (x.wrapping_sub(1), y),
+      (x + 1, y),
+      (x, y.wrapping_sub(1)),
+      (x, y + 1),
+    ]
+    .into_iter()
+    .filter(|&(x, y)| {
+      matches!(self.grid.get(y).and_then(|row| row.get(x)), Some(&previous) if previous + 1 >= elevation)
+    })
+    .collect()
+  }
+
+  fn is_goal(&self, &(x, y): &Position) -> bool {
+    self.grid[y][x] == 0
+  }
+}
+
+/// Fewest steps from the start to the end.
+pub fn part_1(input: &str) -> u32 {
+  let (start, end, grid) = parse(input);
+
+  astar(&Climb { grid: &grid, end }, start).path.unwrap().cost as u32
+}
+
+/// Fewest steps from any square at elevation `a` to the end.
+pub fn part_2(input: &str) -> u32 {
+  let (_, end, grid) = parse(input);
+
+  multi_source_bfs(&Climb { grid: &grid, end }, possible_starts(&grid))
+    .path
+    .unwrap()
+    .cost as u32
+}
+
+/// Same as [`part_2`], but with a single search down from the end.
+pub fn part_2_descent(input: &str) -> u32 {
+  let (_, end, grid) = parse(input);
+
+  bfs(&Descent { grid: &grid }, end).path.unwrap().cost as u32
+}
+
+/// Same as [`part_2`], but with a separate A* search from every square at elevation `a`.
+pub fn part_2_astar_each(input: &str) -> u32 {
+  let (_, end, grid) = parse(input);
+  let climb = Climb { grid: &grid, end };
+
+  possible_starts(&grid)
+    .into_iter()
+    .filter_map(|start| astar(&climb, start).path)
+    .map(|path| path.cost as u32)
+    .min()
+    .unwrap()
+}
+
+/// A square heightmap of `size` squares per side, at least 26, rising from `a` in the first
+/// column to `z` in the last so that every square can climb to the end. The start and the end
+/// are in random rows of the first and last columns.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+  assert!(size >= 26, "the heightmap needs a column per elevation");
+  let start = (0, rng.below(size as u64) as usize);
+  let end = (size - 1, rng.below(size as u64) as usize);
+
+  (0..size)
+    .map(|y| {
+      (0..size)
+        .map(|x| match (x, y) {
+          square if square == start => 'S',
+          square if square == end => 'E',
+          _ => (b'a' + (x * 25 / (size - 1)) as u8) as char,
+        })
+        .collect::<String>()
+    })
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_INPUT: &str = "Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi";
+
+  #[test]
+  fn test_solve_part_1() {
+    let res = part_1(TEST_INPUT);
+    assert_eq!(res, 31);
+  }
+
+  #[test]
+  fn test_solve_part_2() {
+    let res = part_2(TEST_INPUT);
+    assert_eq!(res, 29);
+    assert_eq!(part_2_descent(TEST_INPUT), 29);
+    assert_eq!(part_2_astar_each(TEST_INPUT), 29);
+  }
+
+  #[test]
+  fn test_successors() {
+    // From the `c`, the `a` to its left is two levels down and the `z` is out of reach.
+    let (_, _, grid) = parse("Sacz\nabdE");
+    assert_eq!(successors((2, 0), &grid), [((2, 1), 1), ((1, 0), 1)]);
+  }
+}
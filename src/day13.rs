@@ -0,0 +1,201 @@
+//! Day 13: Distress Signal
+
+use std::cmp::Ordering;
+
+use nom::{
+  branch::alt,
+  bytes::complete::tag,
+  character::complete::{self, newline},
+  combinator::{all_consuming, map},
+  multi::{separated_list0, separated_list1},
+  sequence::{delimited, separated_pair},
+  IResult,
+};
+
+use itertools::Itertools;
+
+use crate::explain::Trace;
+
+/// A packet, ordered as described by the puzzle.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Packet {
+  Num(u32),
+  List(Vec<Packet>),
+}
+
+impl Ord for Packet {
+  fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    match (self, other) {
+      (Self::List(a), Self::List(b)) => a.cmp(b),
+      (Self::List(a), Self::Num(b)) => a.cmp(&vec![Self::Num(*b)]),
+      (Self::Num(a), Self::List(b)) => vec![Self::Num(*a)].cmp(b),
+      (Self::Num(a), Self::Num(b)) => a.cmp(b),
+    }
+  }
+}
+
+impl PartialOrd for Packet {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+/// Parses a single packet such as `[1,[2,3]]`.
+pub fn parse_packet(input: &str) -> IResult<&str, Packet> {
+  alt((
+    map(
+      delimited(tag("["), separated_list0(tag(","), parse_packet), tag("]")),
+      Packet::List,
+    ),
+    map(complete::u32, Packet::Num),
+  ))(input)
+}
+
+/// Parses two packets on consecutive lines.
+pub fn parse_pair(input: &str) -> IResult<&str, (Packet, Packet)> {
+  separated_pair(parse_packet, newline, parse_packet)(input)
+}
+
+/// Parses every pair of packets.
+pub fn parse(input: &str) -> Vec<(Packet, Packet)> {
+  all_consuming(separated_list1(tag("\n\n"), parse_pair))(input)
+    .unwrap()
+    .1
+}
+
+/// Sum of the 1-based indices of the pairs that are in the right order.
+pub fn part_1(input: &str) -> usize {
+  ordered_pairs(&parse(input)).0
+}
+
+/// Decoder key, the product of the positions of the divider packets once sorted.
+pub fn part_2(input: &str) -> usize {
+  decoder_key(&parse(input)).0
+}
+
+/// [`part_1`], with the trace of the order of every pair.
+fn ordered_pairs(pairs: &[(Packet, Packet)]) -> (usize, Vec<Trace>) {
+  let mut trace = vec![];
+  let mut sum = 0;
+  for (idx, (left, right)) in pairs.iter().enumerate() {
+    let order = match left < right {
+      true => {
+        sum += idx + 1;
+        "right order"
+      }
+      false => "wrong order",
+    };
+    trace.push(Trace::leaf(format!("{}", idx + 1), order));
+  }
+
+  trace.push(Trace::leaf("sum of indices", sum));
+  (sum, trace)
+}
+
+/// [`part_2`], with the trace of where the divider packets end up.
+fn decoder_key(pairs: &[(Packet, Packet)]) -> (usize, Vec<Trace>) {
+  let mut packets = pairs
+    .iter()
+    .flat_map(|(left, right)| [left, right])
+    .collect::<Vec<_>>();
+
+  let ((_, divider_packet_2), (_, divider_packet_6)) = (
+    parse_packet("[[2]]").unwrap(),
+    parse_packet("[[6]]").unwrap(),
+  );
+
+  packets.extend([&divider_packet_2, &divider_packet_6]);
+  packets.sort();
+
+  let positions = packets
+    .into_iter()
+    .positions(|packet| packet == &divider_packet_2 || packet == &divider_packet_6)
+    .map(|pos| pos + 1)
+    .collect::<Vec<_>>();
+  let key = positions.iter().product();
+
+  let mut trace = ["[[2]]", "[[6]]"]
+    .into_iter()
+    .zip(&positions)
+    .map(|(divider, position)| Trace::leaf(divider, position))
+    .collect::<Vec<_>>();
+  trace.push(Trace::leaf("decoder key", key));
+  (key, trace)
+}
+
+/// Which pairs are in the right order and where the divider packets end up.
+pub fn explain(input: &str) -> Trace {
+  let pairs = parse(input);
+
+  Trace::new("Day 13").with_children([
+    Trace::new("Part 1").with_children(ordered_pairs(&pairs).1),
+    Trace::new("Part 2").with_children(decoder_key(&pairs).1),
+  ])
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_INPUT: &str = "[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]";
+
+  #[test]
+  fn test_solve_part_1() {
+    let res = part_1(TEST_INPUT);
+    assert_eq!(res, 13);
+  }
+
+  #[test]
+  fn test_solve_part_2() {
+    let res = part_2(TEST_INPUT);
+    assert_eq!(res, 140)
+  }
+
+  #[test]
+  fn test_explain() {
+    let trace = explain(TEST_INPUT);
+    assert_eq!(trace.children[0].children.len(), 9);
+    assert_eq!(
+      trace.children[0].children[..3],
+      [
+        Trace::leaf("1", "right order"),
+        Trace::leaf("2", "right order"),
+        Trace::leaf("3", "wrong order"),
+      ]
+    );
+    assert_eq!(
+      trace.children[0].children[8],
+      Trace::leaf("sum of indices", 13)
+    );
+    assert_eq!(
+      trace.children[1].children,
+      [
+        Trace::leaf("[[2]]", 10),
+        Trace::leaf("[[6]]", 14),
+        Trace::leaf("decoder key", 140),
+      ]
+    );
+  }
+}
@@ -0,0 +1,180 @@
+//! Day 14: Regolith Reservoir
+
+use std::collections::VecDeque;
+
+use crate::{
+  parsers::{coordinate, lines, list, parse_all, unsigned},
+  tile::Tile,
+};
+
+const X_MAX: usize = 1000;
+
+enum Direction {}
+
+impl Direction {
+  fn as_slice() -> [isize; 3] {
+    [0, -1, 1]
+  }
+}
+
+/// What fills a tile of the cave.
+#[derive(Debug, Clone, PartialEq, Eq, Tile)]
+pub enum Material {
+  #[tile('.')]
+  Air,
+  #[tile('#')]
+  Rock,
+  #[tile('o')]
+  Sand,
+  #[tile('+')]
+  SandSource,
+}
+
+/// The horizontal extent of the rock, its lowest row and the cave itself, indexed `[y][x]`.
+pub fn parse(input: &str) -> ((usize, usize), usize, Vec<Vec<Material>>) {
+  let rock_formations = parse_all(
+    lines(list(" -> ", coordinate(",", unsigned::<usize>))),
+    input.trim(),
+  )
+  .unwrap();
+
+  let x_min = rock_formations
+    .iter()
+    .flatten()
+    .map(|(x, _)| x)
+    .min()
+    .unwrap();
+
+  let x_max = rock_formations
+    .iter()
+    .flatten()
+    .map(|(x, _)| x)
+    .max()
+    .unwrap();
+
+  let y_max = rock_formations
+    .iter()
+    .flatten()
+    .map(|(_, y)| y)
+    .max()
+    .unwrap();
+
+  (
+    (*x_min, *x_max),
+    *y_max,
+    rock_formations.iter().fold(
+      vec![vec![Material::Air; X_MAX]; *y_max + 1],
+      |mut grid, rock_formation| {
+        let mut iter = rock_formation.iter().peekable();
+        while let Some((x, y)) = iter.next() {
+          if let Some((next_x, next_y)) = iter.peek() {
+            grid[*y][usize::min(*x, *next_x)..=usize::max(*x, *next_x)].fill(Material::Rock);
+            for row in &mut grid[usize::min(*y, *next_y)..=usize::max(*y, *next_y)] {
+              row[*x] = Material::Rock
+            }
+          }
+        }
+        grid
+      },
+    ),
+  )
+}
+
+fn _print(grid: &Vec<Vec<Material>>) {
+  for row in grid {
+    for col in row {
+      print!("{col}");
+    }
+    println!();
+  }
+  println!();
+}
+
+/// Units of sand that come to rest before sand flows into the abyss.
+pub fn part_1(input: &str) -> u32 {
+  let ((_, x_max), y_max, mut grid) = parse(input);
+  let mut resting = 0;
+  grid[0][500] = Material::SandSource;
+
+  'simulation: loop {
+    let (mut x, mut y) = (500, 0);
+    'sand_drop: loop {
+      // abyss
+      if y + 1 > y_max {
+        break 'simulation;
+      }
+
+      for dx in Direction::as_slice() {
+        let new_x = (x as isize + dx) as usize;
+
+        if x == 0 || x == x_max {
+          break 'simulation;
+        }
+
+        // check if bottom left is air
+        if grid[y + 1][new_x] == Material::Air {
+          x = new_x;
+          y += 1;
+          continue 'sand_drop;
+        }
+      }
+
+      grid[y][x] = Material::Sand;
+      resting += 1;
+      break 'sand_drop;
+    }
+  }
+  resting
+}
+
+/// Units of sand that come to rest once the source is blocked, with a floor below the rock.
+pub fn part_2(input: &str) -> u32 {
+  let ((_, _), y_max, mut grid) = parse(input);
+  let floor = y_max + 2;
+  grid.push(vec![Material::Air; X_MAX]);
+  grid.push(vec![Material::Rock; X_MAX]);
+  let mut resting = 0;
+  let mut queue = VecDeque::new();
+  queue.push_back((500, 0));
+
+  while let Some((x, y)) = queue.pop_front() {
+    if grid[y][x] == Material::Air {
+      resting += 1;
+      grid[y][x] = Material::Sand;
+
+      let next_y = y + 1;
+      if next_y > floor {
+        continue;
+      }
+
+      for dx in Direction::as_slice() {
+        let next_x = (x as isize + dx) as usize;
+        queue.push_back((next_x, next_y))
+      }
+    }
+  }
+
+  resting
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_INPUT: &str = "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9
+
+";
+
+  #[test]
+  fn test_solve_part_1() {
+    let res = part_1(TEST_INPUT);
+    assert_eq!(res, 24);
+  }
+
+  #[test]
+  fn test_solve_part_2() {
+    let res = part_2(TEST_INPUT);
+    assert_eq!(res, 93)
+  }
+}
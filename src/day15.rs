@@ -0,0 +1,360 @@
+//! Day 15: Beacon Exclusion Zone
+
+use std::collections::HashSet;
+use std::{collections::BTreeMap, fmt::Display};
+
+use nom::{
+  bytes::complete::tag,
+  combinator::all_consuming,
+  sequence::{preceded, separated_pair},
+  Finish, IResult,
+};
+
+use serde::Deserialize;
+
+use crate::{
+  grid::{Bounds, SparseGrid},
+  parsers::{field, signed},
+  tile::Tile,
+};
+
+/// Settings of the puzzle that the sample and the real input disagree on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+  /// Row checked in part 1.
+  pub row: usize,
+  /// Largest coordinate the distress beacon can have in part 2.
+  pub bound: usize,
+}
+
+impl Default for Params {
+  fn default() -> Self {
+    Self {
+      row: 2_000_000,
+      bound: 4_000_000,
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Tile)]
+enum State {
+  #[default]
+  #[tile('#')]
+  Covered,
+  #[tile('.')]
+  Air,
+  #[tile('S')]
+  Sensor,
+  #[tile('B')]
+  Beacon,
+}
+
+/// Sensors and the closest beacon each of them detected.
+#[derive(Debug, Clone)]
+pub struct Grid {
+  /// Closest beacon of every sensor, keyed by the sensor.
+  pub grid: BTreeMap<(isize, isize), (isize, isize)>,
+  covered: Vec<[isize; 2]>,
+  /// Row the `covered` intervals lie on.
+  covered_row: isize,
+}
+
+impl Grid {
+  /// Manhattan distance between a sensor and a beacon.
+  pub fn distance(sensor: &(isize, isize), beacon: &(isize, isize)) -> usize {
+    ((sensor.0 - beacon.0).abs() + (sensor.1 - beacon.1).abs()) as usize
+  }
+}
+
+impl From<BTreeMap<(isize, isize), (isize, isize)>> for Grid {
+  fn from(grid: BTreeMap<(isize, isize), (isize, isize)>) -> Self {
+    Self {
+      grid,
+      covered: vec![],
+      covered_row: 0,
+    }
+  }
+}
+
+impl Grid {
+  /// Renders the sensors, beacons and covered positions within `area`. Real inputs span
+  /// millions of positions, so only the cells within it are filled.
+  pub fn viewport(&self, area: Bounds) -> View<'_> {
+    View { grid: self, area }
+  }
+}
+
+/// A rectangle of a [`Grid`], displayed one row per line.
+pub struct View<'a> {
+  grid: &'a Grid,
+  area: Bounds,
+}
+
+impl Display for View<'_> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let Self { grid, area } = self;
+    let mut cells = SparseGrid::new();
+    let row = grid.covered_row as i64;
+    if (area.min[1]..=area.max[1]).contains(&row) {
+      for &[min, max] in &grid.covered {
+        let (min, max) = ((min as i64).max(area.min[0]), (max as i64).min(area.max[0]));
+        cells.extend((min..=max).map(|x| ([x, row], State::Covered)));
+      }
+    }
+    for (sensor, beacon) in &grid.grid {
+      for ((x, y), state) in [(sensor, State::Sensor), (beacon, State::Beacon)] {
+        let point = [*x as i64, *y as i64];
+        if area.contains(point) {
+          cells.insert(point, state);
+        }
+      }
+    }
+
+    write!(f, "{}", cells.viewport(*area, State::Air))
+  }
+}
+
+/// Position of a sensor or beacon as `(x, y)`.
+pub type Coordinate = (isize, isize);
+
+/// Parses an `x=N, y=N` pair.
+pub fn parse_coordinate(input: &str) -> IResult<&str, Coordinate> {
+  separated_pair(field("x", signed), tag(", "), field("y", signed))(input)
+}
+
+/// Parses a line into the sensor and its closest beacon.
+pub fn parse_line(input: &str) -> IResult<&str, (Coordinate, Coordinate)> {
+  separated_pair(
+    preceded(tag("Sensor at "), parse_coordinate),
+    tag(": "),
+    preceded(tag("closest beacon is at "), parse_coordinate),
+  )(input)
+}
+
+/// Parses every sensor report.
+pub fn parse(input: &str) -> Grid {
+  input
+    .trim()
+    .lines()
+    .map(|line| all_consuming(parse_line)(line).finish().unwrap().1)
+    .fold(BTreeMap::new(), |mut grid, (sensor, beacon)| {
+      grid.insert(sensor, beacon);
+      grid
+    })
+    .into()
+}
+
+/// Sorts inclusive `[min, max]` intervals and merges overlapping or adjacent ones.
+pub fn merge(mut covered: Vec<[isize; 2]>) -> Vec<[isize; 2]> {
+  covered.sort();
+
+  let mut merged: Vec<[isize; 2]> = vec![];
+
+  for [min, max] in covered.into_iter() {
+    match merged.last_mut() {
+      Some([_, merged_max]) if min <= *merged_max + 1 => *merged_max = max.max(*merged_max),
+      _ => merged.push([min, max]),
+    }
+  }
+
+  merged
+}
+
+/// Number of positions in `row` where a beacon cannot be present.
+pub fn part_1(input: &str, row: usize) -> usize {
+  let mut grid = parse(input);
+  grid.covered_row = row as isize;
+  let mut known_beacon = HashSet::new();
+
+  for (sensor, beacon) in grid.grid.iter() {
+    let distance = Grid::distance(sensor, beacon) as isize;
+
+    let offset = distance - (sensor.1 - row as isize).abs();
+    // ignore sensor
+    if offset < 0 {
+      continue;
+    }
+
+    let x_min = sensor.0 - offset;
+    let x_max = sensor.0 + offset;
+
+    grid.covered.push([x_min, x_max]);
+
+    if beacon.1 == row as isize {
+      known_beacon.insert(beacon.0);
+    }
+  }
+
+  let merged = merge(grid.covered);
+
+  let mut no_beacon = HashSet::new();
+
+  for [min, max] in merged {
+    for i in min..=max {
+      no_beacon.insert(i);
+    }
+  }
+
+  no_beacon.len() - known_beacon.len()
+}
+
+/// Tuning frequency of the only position within `0..=tuning_frequencies` not covered by
+/// any sensor.
+pub fn part_2(input: &str, tuning_frequencies: usize) -> usize {
+  let mut grid = parse(input);
+  for test_frequency in 0..=tuning_frequencies {
+    grid.covered.clear();
+    grid.covered_row = test_frequency as isize;
+    for (sensor, beacon) in grid.grid.iter() {
+      let distance = Grid::distance(sensor, beacon) as isize;
+
+      let offset = distance - (sensor.1 - test_frequency as isize).abs();
+      // ignore sensor
+      if offset < 0 {
+        continue;
+      }
+
+      let x_min = sensor.0 - offset;
+      let x_max = sensor.0 + offset;
+
+      grid.covered.push([x_min, x_max]);
+    }
+
+    let merged = merge(grid.covered.clone());
+
+    let mut x = 0;
+    for [min, max] in merged {
+      if x < min {
+        return x as usize * 4_000_000 + test_frequency;
+      } else {
+        x = max + 1
+      }
+      if x > tuning_frequencies as isize {
+        break;
+      }
+    }
+  }
+
+  panic!("every position within the bound is covered by a sensor")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_INPUT: &str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3
+
+";
+
+  #[test]
+  fn test_solve_part_1() {
+    let res = part_1(TEST_INPUT, 10);
+    assert_eq!(res, 26);
+  }
+
+  #[test]
+  fn test_solve_part_2() {
+    let res = part_2(TEST_INPUT, 20);
+    assert_eq!(res, 56000011)
+  }
+
+  #[test]
+  fn test_display() {
+    let mut grid = parse("Sensor at x=0, y=2: closest beacon is at x=2, y=0");
+    grid.covered = vec![[-1, 1]];
+    grid.covered_row = 1;
+
+    assert_eq!(
+      grid.viewport(Bounds::new([-1, 0], [2, 2])).to_string(),
+      "...B\n###.\n.S..\n"
+    );
+
+    grid.covered = vec![[-4_000_000_000, 4_000_000_000]];
+    assert_eq!(
+      grid.viewport(Bounds::new([0, 1], [1, 2])).to_string(),
+      "##\nS.\n"
+    );
+  }
+}
+
+#[cfg(test)]
+mod properties {
+  use super::*;
+  use proptest::prelude::*;
+
+  fn render(sensors: &[(Coordinate, Coordinate)]) -> String {
+    sensors
+      .iter()
+      .map(|((sx, sy), (bx, by))| {
+        format!("Sensor at x={sx}, y={sy}: closest beacon is at x={bx}, y={by}\n")
+      })
+      .collect()
+  }
+
+  fn sensors() -> impl Strategy<Value = Vec<(Coordinate, Coordinate)>> {
+    prop::collection::vec(
+      ((-20isize..20, -20isize..20), (-6isize..=6, -6isize..=6)),
+      1..8,
+    )
+    .prop_map(|sensors| {
+      sensors
+        .into_iter()
+        .map(|((sx, sy), (dx, dy))| ((sx, sy), (sx + dx, sy + dy)))
+        .collect()
+    })
+  }
+
+  fn naive_part_1(input: &str, row: isize) -> usize {
+    let grid = parse(input);
+    let beacons = grid
+      .grid
+      .values()
+      .filter(|beacon| beacon.1 == row)
+      .collect::<HashSet<_>>();
+
+    (-100..=100)
+      .filter(|&x| {
+        grid.grid.iter().any(|(sensor, beacon)| {
+          Grid::distance(sensor, &(x, row)) <= Grid::distance(sensor, beacon)
+        })
+      })
+      .filter(|&x| !beacons.contains(&(x, row)))
+      .count()
+  }
+
+  proptest! {
+    #[test]
+    fn merge_covers_same_cells(intervals in prop::collection::vec((-50isize..50, 0isize..20), 0..12)) {
+      let covered = intervals.iter().map(|&(min, len)| [min, min + len]).collect::<Vec<_>>();
+      let merged = merge(covered.clone());
+
+      let expected = covered.iter().flat_map(|&[min, max]| min..=max).collect::<HashSet<_>>();
+      let actual = merged.iter().flat_map(|&[min, max]| min..=max).collect::<HashSet<_>>();
+      prop_assert_eq!(actual, expected);
+
+      for pair in merged.windows(2) {
+        prop_assert!(pair[0][1] + 1 < pair[1][0], "{:?} not disjoint", pair);
+      }
+    }
+
+    #[test]
+    fn part_1_matches_naive(sensors in sensors(), row in 0usize..25) {
+      let input = render(&sensors);
+      prop_assert_eq!(part_1(&input, row), naive_part_1(&input, row as isize));
+    }
+  }
+}
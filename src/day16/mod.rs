@@ -0,0 +1,551 @@
+//! Day 16: Proboscidea Volcanium
+
+use itertools::Itertools;
+use nom::{
+  branch::alt,
+  bytes::complete::{tag, take},
+  character::complete::{self, line_ending},
+  multi::separated_list1,
+  sequence::{preceded, tuple},
+  IResult,
+};
+use std::{
+  cmp::Reverse,
+  collections::{BTreeMap, HashMap},
+};
+
+use crate::{
+  cancel::{Best, Cancellation},
+  checked,
+  search::BoundStats,
+};
+
+const DAY: u8 = 16;
+
+/// A valve and the valves its tunnels lead to.
+#[derive(Debug, Clone)]
+pub struct Valve<'a> {
+  pub flow_rate: u8,
+  pub outlets: Vec<&'a str>,
+}
+
+/// Flow rate of every valve worth visiting, `AA` first.
+pub type FlowRates = Vec<u8>;
+/// Minutes needed to walk between any two valves, indexed like [`FlowRates`].
+pub type DistancesMatrix = Vec<Vec<u8>>;
+
+/// Shortest distances between all valves, in the order of the map.
+pub fn floyd_warshall(valves: &BTreeMap<&str, Valve>) -> DistancesMatrix {
+  let valve_index_lookup: HashMap<&str, _> = valves
+    .iter()
+    .enumerate()
+    .map(|(i, (id, _))| (*id, i))
+    .collect();
+
+  let mut dist = vec![vec![u8::MAX; valves.len()]; valves.len()];
+  for (i, (_, valves)) in valves.iter().enumerate() {
+    for outlet in valves.outlets.iter() {
+      let j = valve_index_lookup[&outlet[..]];
+      dist[i][j] = 1;
+    }
+  }
+  (0..dist.len()).for_each(|i| {
+    dist[i][i] = 0;
+  });
+  for k in 0..dist.len() {
+    for i in 0..dist.len() {
+      for j in 0..dist.len() {
+        // `u8::MAX` stands for no path yet.
+        if dist[i][k] == u8::MAX || dist[k][j] == u8::MAX {
+          continue;
+        }
+        let through = checked::add(DAY, dist[i][k], dist[k][j]);
+        if dist[i][j] > through {
+          dist[i][j] = through;
+        }
+      }
+    }
+  }
+  dist
+}
+
+/// Parses a single `Valve XX has flow rate=N; ...` line.
+pub fn parse_valve(input: &str) -> IResult<&str, (&str, Valve<'_>)> {
+  let (input, (id, flow_rate, outlets)) = tuple((
+    preceded(tag("Valve "), take(2usize)),
+    preceded(tag(" has flow rate="), complete::u8),
+    preceded(
+      alt((
+        tag("; tunnels lead to valves "),
+        tag("; tunnel leads to valve "),
+      )),
+      separated_list1(tag(", "), take(2usize)),
+    ),
+  ))(input)?;
+  Ok((input, (id, Valve { flow_rate, outlets })))
+}
+
+/// Parses the scan and reduces it to `AA` and the valves with a positive flow rate.
+pub fn parse(input: &str) -> (FlowRates, DistancesMatrix) {
+  let valves = separated_list1(line_ending, parse_valve)(input)
+    .unwrap()
+    .1
+    .into_iter()
+    .collect::<BTreeMap<&str, Valve>>();
+
+  let distances_matrix = floyd_warshall(&valves);
+
+  let interesting_valves = valves
+    .iter()
+    .enumerate()
+    .filter(|(_, (&id, valve))| id == "AA" || valve.flow_rate > 0)
+    .map(|(i, _)| i)
+    .collect_vec();
+
+  let flow_rates = valves
+    .iter()
+    .enumerate()
+    .filter(|(i, (_, _))| interesting_valves.contains(i))
+    .map(|(_, (_, valve))| valve.flow_rate)
+    .collect_vec();
+
+  let distances_matrix = interesting_valves
+    .iter()
+    .map(|&i| {
+      interesting_valves
+        .iter()
+        .map(|&j| distances_matrix[i][j])
+        .collect()
+    })
+    .collect();
+
+  (flow_rates, distances_matrix)
+}
+
+#[derive(Default, Debug, Clone, Copy)]
+struct VisitorState {
+  next: u8,
+  eta: u8,
+}
+
+#[derive(Default, Debug, Clone, Copy)]
+struct State {
+  visitors: [VisitorState; 2],
+  visited: u16,
+  pressure_released: u16,
+  current_flow: u16,
+  minutes_remaining: u8,
+}
+
+impl State {
+  fn visited(&self, i: usize) -> bool {
+    self.visited & (1 << i) != 0
+  }
+
+  fn solution(&self) -> Option<u16> {
+    (!self.visitors.iter().any(|v| v.eta < self.minutes_remaining)).then(|| {
+      let minutes = checked::add(DAY, self.minutes_remaining as u16, 1);
+      checked::add(
+        DAY,
+        self.pressure_released,
+        checked::mul(DAY, minutes, self.current_flow),
+      )
+    })
+  }
+
+  fn bound(mut self, flow_rates: &FlowRates) -> u16 {
+    let mut remaining_flow_rate_indices = (0..flow_rates.len())
+      .filter(|&i| !self.visited(i) && !self.visitors.iter().any(|v| v.next == i as u8))
+      .collect_vec();
+    remaining_flow_rate_indices.sort_unstable_by_key(|&i| flow_rates[i]);
+    while self.minutes_remaining > 0 {
+      self.minutes_remaining -= 1;
+      self.pressure_released = checked::add(DAY, self.pressure_released, self.current_flow);
+      for visitor in self.visitors.iter_mut() {
+        if visitor.eta > 0 {
+          visitor.eta -= 1;
+          continue;
+        }
+        self.current_flow = checked::add(
+          DAY,
+          self.current_flow,
+          flow_rates[visitor.next as usize] as u16,
+        );
+        if let Some(i) = remaining_flow_rate_indices.pop() {
+          visitor.next = i as u8;
+          visitor.eta = 1;
+        } else {
+          visitor.eta = u8::MAX;
+        }
+      }
+    }
+    checked::add(DAY, self.pressure_released, self.current_flow)
+  }
+
+  fn branch(
+    mut self,
+    flow_rates: &FlowRates,
+    shortest_path_lengths: &DistancesMatrix,
+  ) -> impl IntoIterator<Item = Self> {
+    self.pressure_released = checked::add(DAY, self.pressure_released, self.current_flow);
+    self.minutes_remaining -= 1;
+    let mut branches = vec![self];
+    for (visitor_idx, visitor) in self.visitors.into_iter().enumerate() {
+      if visitor.eta > 0 {
+        branches
+          .iter_mut()
+          .for_each(|state| state.visitors[visitor_idx].eta -= 1);
+        continue;
+      }
+      branches.iter_mut().for_each(|state| {
+        state.visited |= 1 << visitor.next;
+        state.current_flow = checked::add(
+          DAY,
+          state.current_flow,
+          flow_rates[visitor.next as usize] as u16,
+        );
+      });
+      branches = branches
+        .iter()
+        .flat_map(|&state| {
+          shortest_path_lengths[visitor.next as usize]
+            .iter()
+            .enumerate()
+            .filter(move |&(destination, _)| {
+              !state.visited(destination)
+                && !state.visitors.iter().any(|v| v.next == destination as u8)
+            })
+            .map(move |(destination, &distance)| {
+              let mut next_state = state;
+              next_state.visitors[visitor_idx].next = destination as u8;
+              next_state.visitors[visitor_idx].eta = distance;
+              next_state
+            })
+        })
+        .chain([{
+          let mut state = branches[0];
+          state.visitors[visitor_idx].eta = u8::MAX;
+          state
+        }])
+        .collect();
+    }
+    branches
+  }
+}
+
+/// The best answer so far, and how the search is going.
+struct Search<'a> {
+  best: u16,
+  cancellation: &'a Cancellation,
+  stats: &'a mut BoundStats,
+}
+
+/// Returns `false` if the search was cancelled before exploring every promising branch.
+fn branch_and_bound(
+  flow_rates: &FlowRates,
+  shortest_path_lengths: &DistancesMatrix,
+  state: State,
+  depth: usize,
+  search: &mut Search,
+) -> bool {
+  if search.cancellation.is_cancelled() {
+    return false;
+  }
+  if let Some(solution) = state.solution() {
+    search.stats.solution(solution > search.best);
+    search.best = solution.max(search.best);
+    return true;
+  }
+  search.stats.expand(depth);
+  let bound_branch_pairs = state
+    .branch(flow_rates, shortest_path_lengths)
+    .into_iter()
+    .map(|state| (state.bound(flow_rates), state))
+    .filter(|(bound, _)| {
+      let promising = *bound > search.best;
+      if !promising {
+        search.stats.prune();
+      }
+      promising
+    })
+    .sorted_unstable_by_key(|(bound, _)| Reverse(*bound))
+    .collect_vec();
+  for (bound, branch) in bound_branch_pairs {
+    // The best answer may have improved since the branches were bounded.
+    if bound <= search.best {
+      search.stats.prune();
+      continue;
+    }
+    if !branch_and_bound(flow_rates, shortest_path_lengths, branch, depth + 1, search) {
+      return false;
+    }
+  }
+  true
+}
+
+fn search(
+  (flow_rates, distances_matrix): &(FlowRates, DistancesMatrix),
+  state: State,
+  cancellation: &Cancellation,
+  stats: &mut BoundStats,
+) -> Best<u16> {
+  let mut search = Search {
+    best: 0,
+    cancellation,
+    stats,
+  };
+  let optimal = branch_and_bound(flow_rates, distances_matrix, state, 0, &mut search);
+  Best {
+    value: search.best,
+    optimal,
+  }
+}
+
+/// Most pressure that can be released alone in 30 minutes.
+pub fn part_1(parsed: &(FlowRates, DistancesMatrix)) -> u16 {
+  part_1_within(parsed, &Cancellation::new(), &mut BoundStats::start()).value
+}
+
+/// [`part_1`], stopping with the best plan found so far once `cancellation` fires, and
+/// recording how the search pruned in `stats`.
+pub fn part_1_within(
+  parsed: &(FlowRates, DistancesMatrix),
+  cancellation: &Cancellation,
+  stats: &mut BoundStats,
+) -> Best<u16> {
+  search(parsed, alone(), cancellation, stats)
+}
+
+fn alone() -> State {
+  State {
+    visitors: [
+      VisitorState { next: 0, eta: 0 },
+      VisitorState {
+        next: 0,
+        eta: u8::MAX,
+      },
+    ],
+    visited: 0,
+    pressure_released: 0,
+    current_flow: 0,
+    minutes_remaining: 30,
+  }
+}
+
+/// Most pressure that can be released together with an elephant in 26 minutes.
+pub fn part_2(parsed: &(FlowRates, DistancesMatrix)) -> u16 {
+  part_2_within(parsed, &Cancellation::new(), &mut BoundStats::start()).value
+}
+
+/// [`part_2`], stopping with the best plan found so far once `cancellation` fires, and
+/// recording how the search pruned in `stats`.
+pub fn part_2_within(
+  parsed: &(FlowRates, DistancesMatrix),
+  cancellation: &Cancellation,
+  stats: &mut BoundStats,
+) -> Best<u16> {
+  search(parsed, with_elephant(), cancellation, stats)
+}
+
+fn with_elephant() -> State {
+  State {
+    visitors: [
+      VisitorState { next: 0, eta: 0 },
+      VisitorState { next: 0, eta: 0 },
+    ],
+    visited: 0,
+    pressure_released: 0,
+    current_flow: 0,
+    minutes_remaining: 26,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  const TEST_INPUT: &str = include_str!("sample");
+
+  #[test]
+  fn test_solve_part_1() {
+    assert_eq!(part_1(&parse(TEST_INPUT)), 1651);
+  }
+
+  #[test]
+  fn test_solve_part_2() {
+    assert_eq!(part_2(&parse(TEST_INPUT)), 1707);
+  }
+
+  #[test]
+  fn test_cancelled() {
+    let cancellation = Cancellation::new();
+    cancellation.cancel();
+    let best = part_2_within(&parse(TEST_INPUT), &cancellation, &mut BoundStats::start());
+    assert!(!best.optimal);
+    assert!(best.value < 1707);
+  }
+
+  #[test]
+  fn test_stats() {
+    let parsed = parse(TEST_INPUT);
+    for within in [part_1_within, part_2_within] {
+      let mut stats = BoundStats::start();
+      assert!(within(&parsed, &Cancellation::new(), &mut stats).optimal);
+      assert!(stats.expanded > stats.max_depth);
+      assert!(stats.pruned > 0);
+      assert!(stats.improvements > 0);
+      assert!(stats.first_solution.is_some());
+    }
+  }
+}
+
+#[cfg(test)]
+mod properties {
+  use super::*;
+  use proptest::prelude::*;
+  use std::collections::VecDeque;
+
+  #[derive(Debug, Clone)]
+  struct Cave {
+    flow_rates: Vec<u8>,
+    tunnels: Vec<Vec<usize>>,
+  }
+
+  fn name(i: usize) -> String {
+    let c = (b'A' + i as u8) as char;
+    format!("{c}{c}")
+  }
+
+  fn render(cave: &Cave) -> String {
+    cave
+      .flow_rates
+      .iter()
+      .zip(cave.tunnels.iter())
+      .enumerate()
+      .map(|(i, (flow_rate, tunnels))| {
+        format!(
+          "Valve {} has flow rate={}; tunnels lead to valves {}",
+          name(i),
+          flow_rate,
+          tunnels.iter().map(|&j| name(j)).join(", ")
+        )
+      })
+      .join("\n")
+  }
+
+  // A random spanning tree plus a few extra edges keeps every valve reachable from AA.
+  fn caves() -> impl Strategy<Value = Cave> {
+    (2usize..=7)
+      .prop_flat_map(|n| {
+        (
+          prop::collection::vec(prop_oneof![Just(0u8), 1u8..25], n - 1),
+          prop::collection::vec(any::<prop::sample::Index>(), n - 1),
+          prop::collection::vec((0..n, 0..n), 0..n),
+        )
+      })
+      .prop_map(|(flow_rates, parents, extra)| {
+        let n = flow_rates.len() + 1;
+        let mut tunnels = vec![vec![]; n];
+        let edges = parents
+          .iter()
+          .enumerate()
+          .map(|(i, parent)| (i + 1, parent.index(i + 1)))
+          .chain(extra.into_iter().filter(|(a, b)| a != b));
+        for (a, b) in edges {
+          if !tunnels[a].contains(&b) {
+            tunnels[a].push(b);
+            tunnels[b].push(a);
+          }
+        }
+        Cave {
+          flow_rates: [0].into_iter().chain(flow_rates).collect(),
+          tunnels,
+        }
+      })
+  }
+
+  fn distances(cave: &Cave, from: usize) -> Vec<u32> {
+    let mut distances = vec![u32::MAX; cave.tunnels.len()];
+    let mut queue = VecDeque::from([from]);
+    distances[from] = 0;
+    while let Some(valve) = queue.pop_front() {
+      for &next in &cave.tunnels[valve] {
+        if distances[next] == u32::MAX {
+          distances[next] = distances[valve] + 1;
+          queue.push_back(next);
+        }
+      }
+    }
+    distances
+  }
+
+  /// Best pressure for every set of opened valves, found by trying every opening order.
+  fn best_per_opened_set(cave: &Cave, minutes: u32) -> HashMap<u32, u32> {
+    fn visit(
+      cave: &Cave,
+      distances: &[Vec<u32>],
+      at: usize,
+      remaining: u32,
+      opened: u32,
+      released: u32,
+      best: &mut HashMap<u32, u32>,
+    ) {
+      let entry = best.entry(opened).or_default();
+      *entry = (*entry).max(released);
+      for next in 0..cave.flow_rates.len() {
+        let cost = distances[at][next] + 1;
+        if cave.flow_rates[next] == 0 || opened & (1 << next) != 0 || cost >= remaining {
+          continue;
+        }
+        let remaining = remaining - cost;
+        visit(
+          cave,
+          distances,
+          next,
+          remaining,
+          opened | (1 << next),
+          released + remaining * cave.flow_rates[next] as u32,
+          best,
+        );
+      }
+    }
+
+    let distances = (0..cave.tunnels.len())
+      .map(|i| distances(cave, i))
+      .collect_vec();
+    let mut best = HashMap::new();
+    visit(cave, &distances, 0, minutes, 0, 0, &mut best);
+    best
+  }
+
+  fn naive_part_1(cave: &Cave) -> u32 {
+    best_per_opened_set(cave, 30).into_values().max().unwrap()
+  }
+
+  fn naive_part_2(cave: &Cave) -> u32 {
+    let best = best_per_opened_set(cave, 26);
+    best
+      .iter()
+      .flat_map(|(me, a)| {
+        best
+          .iter()
+          .filter(move |(elephant, _)| *me & **elephant == 0)
+          .map(move |(_, b)| a + b)
+      })
+      .max()
+      .unwrap()
+  }
+
+  proptest! {
+    #[test]
+    fn part_1_matches_naive(cave in caves()) {
+      let input = render(&cave);
+      prop_assert_eq!(part_1(&parse(&input)) as u32, naive_part_1(&cave), "{}", input);
+    }
+
+    #[test]
+    fn part_2_matches_naive(cave in caves()) {
+      let input = render(&cave);
+      prop_assert_eq!(part_2(&parse(&input)) as u32, naive_part_2(&cave), "{}", input);
+    }
+  }
+}
@@ -0,0 +1,301 @@
+//! Day 17: Pyroclastic Flow
+
+use nom::{
+  character::complete::{self, line_ending},
+  combinator::map_res,
+  multi::{many1, many_m_n, separated_list1},
+  IResult,
+};
+
+use serde::Deserialize;
+
+use crate::{
+  cycle::{simulate, NoCycle},
+  tile::Tile,
+};
+
+const CHAMBER_WIDTH: usize = 7;
+const SURFACE_DEPTH: usize = 64;
+/// Rocks dropped looking for a cycle before giving up. Real inputs repeat within a few
+/// thousand rocks.
+const CYCLE_LIMIT: usize = 1_000_000;
+
+/// Number of rocks dropped in each part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+  pub rocks_1: usize,
+  pub rocks_2: usize,
+}
+
+impl Default for Params {
+  fn default() -> Self {
+    Self {
+      rocks_1: 2022,
+      rocks_2: 1_000_000_000_000,
+    }
+  }
+}
+
+/// The shapes of the falling rocks, in the order they fall.
+pub const ROCK_FORMATIONS: &str = include_str!("rock_formations");
+
+/// A rock shape, anchored at its top-left corner.
+pub type RockFormation = [[Material; 4]; 4];
+
+/// What fills a tile of the chamber.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Tile)]
+pub enum Material {
+  #[tile('.')]
+  Air,
+  #[tile('#')]
+  Rock,
+}
+
+/// A jet of hot gas pushing the falling rock.
+#[derive(Debug)]
+pub enum Movement {
+  Left,
+  Right,
+}
+
+impl From<char> for Movement {
+  fn from(value: char) -> Self {
+    match value {
+      '>' => Self::Right,
+      '<' => Self::Left,
+      _ => unreachable!(),
+    }
+  }
+}
+
+impl From<&Movement> for isize {
+  fn from(value: &Movement) -> Self {
+    match value {
+      Movement::Left => -1,
+      Movement::Right => 1,
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+struct Chamber {
+  fields: Vec<[Material; CHAMBER_WIDTH + 2]>,
+  ceiling: usize,
+  /// Rocks dropped so far.
+  rocks: usize,
+  /// Jets of gas used so far.
+  jets: usize,
+  /// Whether the last rock fell below the rows captured by [`Chamber::surface`].
+  fell_deep: bool,
+}
+
+impl Default for Chamber {
+  fn default() -> Self {
+    let mut chamber = Self {
+      fields: vec![[Material::Rock; CHAMBER_WIDTH + 2]; 1],
+      ceiling: 0,
+      rocks: 0,
+      jets: 0,
+      fell_deep: false,
+    };
+    chamber.add_rows();
+    chamber
+  }
+}
+
+impl Chamber {
+  fn add_rows(&mut self) {
+    self.fields.resize(
+      self.ceiling + 8,
+      [
+        Material::Rock,
+        Material::Air,
+        Material::Air,
+        Material::Air,
+        Material::Air,
+        Material::Air,
+        Material::Air,
+        Material::Air,
+        Material::Rock,
+      ],
+    );
+  }
+
+  fn collision(&self, rock_formation: &RockFormation, x: usize, y: usize) -> bool {
+    rock_formation.iter().enumerate().any(|(dy, row)| {
+      row.iter().enumerate().any(|(dx, material)| {
+        *material == Material::Rock && self.fields[y + dy][x + dx] == Material::Rock
+      })
+    })
+  }
+
+  /// The topmost `SURFACE_DEPTH` rows, one bit per tile. Together with the next rock and jet
+  /// this is what the rest of the simulation depends on, as long as no rock falls deeper.
+  fn surface(&self) -> [u8; SURFACE_DEPTH] {
+    let mut surface = [u8::MAX; SURFACE_DEPTH];
+    for (row, bits) in self.fields[..=self.ceiling]
+      .iter()
+      .rev()
+      .zip(surface.iter_mut())
+    {
+      *bits = row[1..=CHAMBER_WIDTH].iter().fold(0, |bits, material| {
+        bits << 1 | (*material == Material::Rock) as u8
+      });
+    }
+    surface
+  }
+
+  fn drop_rock(&mut self, rock_formations: &[RockFormation], movements: &[Movement]) {
+    let rock_formation = &rock_formations[self.rocks % rock_formations.len()];
+    self.rocks += 1;
+    self.fell_deep = false;
+    self.add_rows();
+
+    let mut y = self.ceiling + 3 + 1;
+    let mut x = 3isize;
+    loop {
+      let movement = &movements[self.jets % movements.len()];
+      self.jets += 1;
+      let new_x = x + isize::from(movement);
+      if !self.collision(rock_formation, new_x as usize, y) {
+        x = new_x;
+      }
+      let new_y = y - 1;
+      if new_y + SURFACE_DEPTH <= self.ceiling {
+        self.fell_deep = true;
+      }
+      if self.collision(rock_formation, x as usize, new_y) {
+        break;
+      } else {
+        y = new_y;
+      }
+    }
+
+    for (dy, row) in rock_formation.iter().enumerate() {
+      for (dx, material) in row.iter().enumerate() {
+        if *material == Material::Rock {
+          self.fields[y + dy][x as usize + dx] = Material::Rock;
+          self.ceiling = self.ceiling.max(y + dy);
+        }
+      }
+    }
+    self.add_rows();
+  }
+}
+
+/// Parses the jet pattern.
+pub fn parse_movement(input: &str) -> Vec<Movement> {
+  input.trim().chars().map(Into::into).collect()
+}
+
+fn parse_rock(input: &str) -> IResult<&str, Vec<Material>> {
+  many1(map_res(complete::anychar, Material::try_from))(input)
+}
+
+fn parse_formation(input: &str) -> IResult<&str, RockFormation> {
+  let (input, formation) = separated_list1(line_ending, parse_rock)(input)?;
+  let mut filled_formation = [[Material::Air; 4]; 4];
+
+  for (row, rock_row) in formation.iter().enumerate() {
+    for (col, material) in rock_row.iter().enumerate() {
+      filled_formation[row][col] = *material
+    }
+  }
+
+  Ok((input, filled_formation))
+}
+
+/// Parses blank-line-separated rock shapes such as [`ROCK_FORMATIONS`].
+pub fn parse_formations(input: &str) -> IResult<&str, Vec<RockFormation>> {
+  separated_list1(many_m_n(2, 2, line_ending), parse_formation)(input)
+}
+
+/// Height of the tower after `amount` rocks have come to rest.
+pub fn solve(
+  movements: &[Movement],
+  rock_formations: &[RockFormation],
+  amount: usize,
+) -> Result<usize, NoCycle> {
+  let timeline = simulate(
+    Chamber::default(),
+    |chamber| {
+      // Two states only repeat if no rock looked past the surface in between.
+      (!chamber.fell_deep).then(|| {
+        (
+          chamber.rocks % rock_formations.len(),
+          chamber.jets % movements.len(),
+          chamber.surface(),
+        )
+      })
+    },
+    |chamber| chamber.ceiling,
+    |chamber| chamber.drop_rock(rock_formations, movements),
+    amount,
+    CYCLE_LIMIT,
+  )?;
+  Ok(timeline.at(amount).unwrap())
+}
+
+/// Height of the tower after `rocks` rocks, 2022 in the puzzle.
+pub fn part_1(input: &str, rocks: usize) -> usize {
+  let movements = parse_movement(input);
+  let rock_formations = parse_formations(ROCK_FORMATIONS).unwrap().1;
+
+  solve(&movements, &rock_formations, rocks).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Height of the tower after `rocks` rocks, 1000000000000 in the puzzle.
+pub fn part_2(input: &str, rocks: usize) -> usize {
+  let movements = parse_movement(input);
+  let rock_formations = parse_formations(ROCK_FORMATIONS).unwrap().1;
+
+  solve(&movements, &rock_formations, rocks).unwrap_or_else(|err| panic!("{err}"))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_INPUT: &str = include_str!("sample");
+
+  #[test]
+  fn test_solve_part_1() {
+    let res = part_1(TEST_INPUT.trim(), Params::default().rocks_1);
+    assert_eq!(res, 3068);
+  }
+
+  #[test]
+  fn test_solve_part_2() {
+    let res = part_2(TEST_INPUT.trim(), Params::default().rocks_2);
+    assert_eq!(res, 1514285714288)
+  }
+}
+
+#[cfg(test)]
+mod properties {
+  use super::*;
+  use proptest::prelude::*;
+
+  /// Drops every rock one by one, without looking for a cycle.
+  fn naive_solve(movements: &[Movement], amount: usize) -> usize {
+    let rock_formations = parse_formations(ROCK_FORMATIONS).unwrap().1;
+    let mut chamber = Chamber::default();
+    for _ in 0..amount {
+      chamber.drop_rock(&rock_formations, movements);
+    }
+    chamber.ceiling
+  }
+
+  proptest! {
+    #[test]
+    fn solve_matches_naive(jets in "[<>]{1,40}", amount in 1usize..2500) {
+      let movements = parse_movement(&jets);
+      let rock_formations = parse_formations(ROCK_FORMATIONS).unwrap().1;
+      prop_assert_eq!(
+        solve(&movements, &rock_formations, amount).unwrap(),
+        naive_solve(&movements, amount)
+      );
+    }
+  }
+}
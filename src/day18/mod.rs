@@ -0,0 +1,51 @@
+//! Day 18: Boiling Boulders
+
+#![allow(unused_variables)]
+
+use nom::combinator::map_opt;
+
+use crate::{
+  parsers::{lines, list, parse_all, signed, ParseError},
+  voxel::{Dense, Voxels},
+};
+
+/// The cubes of the scanned droplet.
+pub fn parse(input: &str) -> Result<Dense<()>, ParseError> {
+  let cube = map_opt(list(",", signed), |coords| coords.try_into().ok());
+
+  Ok(
+    parse_all(lines(cube), input.trim())?
+      .into_iter()
+      .map(|cube| (cube, ()))
+      .collect(),
+  )
+}
+
+/// Surface area of the scanned droplet, including air pockets inside.
+pub fn part_1(input: &str) -> usize {
+  parse(input).unwrap().surface_area()
+}
+
+/// Exterior surface area of the scanned droplet.
+pub fn part_2(input: &str) -> usize {
+  parse(input).unwrap().exterior_surface_area()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_INPUT: &str = include_str!("sample");
+
+  #[test]
+  fn test_solve_part_1() {
+    let res = part_1(TEST_INPUT);
+    assert_eq!(res, 64);
+  }
+
+  #[test]
+  fn test_solve_part_2() {
+    let res = part_2(TEST_INPUT);
+    assert_eq!(res, 58)
+  }
+}
@@ -0,0 +1,408 @@
+//! Day 19: Not Enough Minerals
+
+use std::{convert::Infallible, str::FromStr};
+
+use itertools::Itertools;
+
+use crate::{
+  cancel::{Best, Cancellation},
+  explain::Trace,
+  search::BoundStats,
+};
+
+#[derive(Debug)]
+struct State {
+  ores: [u32; 4],
+  robots: [u32; 4],
+  time: u32,
+}
+
+impl Default for State {
+  fn default() -> Self {
+    Self {
+      ores: [0; 4],
+      robots: [1, 0, 0, 0],
+      time: 0,
+    }
+  }
+}
+
+#[derive(Debug)]
+enum Material {
+  Ore,
+  Clay,
+  Obsidian,
+}
+
+/// The costs of building each kind of robot.
+#[derive(Debug)]
+pub struct Blueprint {
+  pub id: usize,
+  /// Ore, clay and obsidian needed for an ore, clay, obsidian and geode robot.
+  pub recipes: [[u32; 4]; 4],
+  max_spend: [u32; 4],
+}
+
+impl FromStr for Blueprint {
+  type Err = Infallible;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let mut max_spend = [0; 4];
+    let (id, ore_ore, clay_ore, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian) = s
+      .split(&[' ', ':'])
+      .filter_map(|s| s.parse().ok())
+      .collect_tuple()
+      .unwrap();
+
+    max_spend[Material::Ore as usize] = max_spend[Material::Ore as usize]
+      .max(ore_ore)
+      .max(clay_ore)
+      .max(obsidian_ore)
+      .max(geode_ore);
+
+    max_spend[Material::Clay as usize] = max_spend[Material::Clay as usize].max(obsidian_clay);
+
+    max_spend[Material::Obsidian as usize] =
+      max_spend[Material::Obsidian as usize].max(geode_obsidian);
+
+    max_spend[3] = u32::MAX;
+
+    Ok(Blueprint {
+      id: id as usize,
+      recipes: [
+        [ore_ore, 0, 0, 0],
+        [clay_ore, 0, 0, 0],
+        [obsidian_ore, obsidian_clay, 0, 0],
+        [geode_ore, 0, geode_obsidian, 0],
+      ],
+      max_spend,
+    })
+  }
+}
+
+impl Blueprint {
+  /// Most geodes that can be opened in the given number of minutes.
+  pub fn max_geodes(&self, remaining_minutes: u32) -> u32 {
+    self
+      .max_geodes_within(
+        remaining_minutes,
+        &Cancellation::new(),
+        &mut BoundStats::start(),
+      )
+      .value
+  }
+
+  /// [`max_geodes`](Self::max_geodes), stopping with the best plan found so far once
+  /// `cancellation` fires, and adding how the search pruned to `stats`.
+  pub fn max_geodes_within(
+    &self,
+    remaining_minutes: u32,
+    cancellation: &Cancellation,
+    stats: &mut BoundStats,
+  ) -> Best<u32> {
+    let mut max_geodes = 0;
+    let optimal = self.recurse_simulation(
+      State::default(),
+      remaining_minutes,
+      &mut max_geodes,
+      0,
+      &mut Search {
+        cancellation,
+        stats,
+      },
+    );
+    Best {
+      value: max_geodes,
+      optimal,
+    }
+  }
+
+  /// Returns `false` if the search was cancelled before exploring every promising branch.
+  fn recurse_simulation(
+    &self,
+    state: State,
+    max_time: u32,
+    max_geodes: &mut u32,
+    depth: usize,
+    search: &mut Search,
+  ) -> bool {
+    if search.cancellation.is_cancelled() {
+      return false;
+    }
+    search.stats.expand(depth);
+    let mut has_recursed = false;
+    for i in 0..4 {
+      if state.robots[i] == self.max_spend[i] {
+        continue;
+      }
+      let recipe = &self.recipes[i];
+      let wait_time = (0..3)
+        .filter_map(|ore_type| {
+          if recipe[ore_type] == 0 {
+            None
+          } else if recipe[ore_type] <= state.ores[ore_type] {
+            Some(0)
+          } else if state.robots[ore_type] == 0 {
+            Some(max_time + 1)
+          } else {
+            Some((recipe[ore_type] - state.ores[ore_type]).div_ceil(state.robots[ore_type]))
+          }
+        })
+        .max()
+        .unwrap();
+      let time_finished = state.time + wait_time + 1;
+      if time_finished >= max_time {
+        continue;
+      }
+      let mut new_ores = [0; 4];
+      let mut new_robots = [0; 4];
+      for o in 0..4 {
+        new_ores[o] = state.ores[o] + state.robots[o] * (wait_time + 1) - recipe[o];
+        new_robots[o] = state.robots[o] + u32::from(o == i);
+      }
+      let remaining_time = max_time - time_finished;
+      if ((remaining_time - 1) * remaining_time) / 2 + new_ores[3] + remaining_time * new_robots[3]
+        < *max_geodes
+      {
+        search.stats.prune();
+        continue;
+      }
+      has_recursed = true;
+      if !self.recurse_simulation(
+        State {
+          ores: new_ores,
+          robots: new_robots,
+          time: time_finished,
+        },
+        max_time,
+        max_geodes,
+        depth + 1,
+        search,
+      ) {
+        return false;
+      }
+    }
+    if !has_recursed {
+      let geodes = state.ores[3] + state.robots[3] * (max_time - state.time);
+      search.stats.solution(geodes > *max_geodes);
+      *max_geodes = geodes.max(*max_geodes);
+    }
+    true
+  }
+}
+
+/// When to stop a search, and how it is going.
+struct Search<'a> {
+  cancellation: &'a Cancellation,
+  stats: &'a mut BoundStats,
+}
+
+/// Parses one blueprint per line.
+pub fn parse(input: &str) -> Vec<Blueprint> {
+  input
+    .trim()
+    .lines()
+    .map(FromStr::from_str)
+    .filter_map(|s| s.ok())
+    .collect()
+}
+
+/// Sum of the quality levels of all blueprints after 24 minutes.
+pub fn part_1(input: &str) -> usize {
+  part_1_within(input, &Cancellation::new(), &mut BoundStats::start()).value
+}
+
+/// [`part_1`], using the best plans found so far once `cancellation` fires. Every blueprint
+/// gets its share of the time left, so that none is left unsearched. `stats` adds up the
+/// searches of every blueprint.
+pub fn part_1_within(
+  input: &str,
+  cancellation: &Cancellation,
+  stats: &mut BoundStats,
+) -> Best<usize> {
+  quality_levels(&parse(input), cancellation, stats).0
+}
+
+/// [`part_1_within`], with the trace of the geodes and quality level of every blueprint.
+fn quality_levels(
+  blueprints: &[Blueprint],
+  cancellation: &Cancellation,
+  stats: &mut BoundStats,
+) -> (Best<usize>, Vec<Trace>) {
+  let mut total = Best::optimal(0);
+  let mut trace = vec![];
+  for (idx, bp) in blueprints.iter().enumerate() {
+    let share = cancellation.share(blueprints.len() - idx);
+    let geodes = bp.max_geodes_within(24, &share, stats);
+    let quality = geodes.map(|geodes| geodes as usize * bp.id);
+    trace.push(Trace::new(format!("Blueprint {}", bp.id)).with_children([
+      Trace::leaf("geodes", geodes),
+      Trace::leaf("quality level", quality),
+    ]));
+    total = Best {
+      value: total.value + quality.value,
+      optimal: total.optimal && quality.optimal,
+    };
+  }
+
+  trace.push(Trace::leaf("sum", total));
+  (total, trace)
+}
+
+/// Product of the geodes opened with the first three blueprints after 32 minutes.
+pub fn part_2(input: &str) -> usize {
+  part_2_within(input, &Cancellation::new(), &mut BoundStats::start()).value
+}
+
+/// [`part_2`], using the best plans found so far once `cancellation` fires. Each of the three
+/// blueprints gets its share of the time left. `stats` adds up their searches.
+pub fn part_2_within(
+  input: &str,
+  cancellation: &Cancellation,
+  stats: &mut BoundStats,
+) -> Best<usize> {
+  geodes_product(&parse(input), cancellation, stats).0
+}
+
+/// [`part_2_within`], with the trace of the geodes opened with each of the three blueprints.
+fn geodes_product(
+  blueprints: &[Blueprint],
+  cancellation: &Cancellation,
+  stats: &mut BoundStats,
+) -> (Best<usize>, Vec<Trace>) {
+  let mut product = Best::optimal(1);
+  let mut trace = vec![];
+  let blueprints = &blueprints[..blueprints.len().min(3)];
+  for (idx, bp) in blueprints.iter().enumerate() {
+    let share = cancellation.share(blueprints.len() - idx);
+    let geodes = bp.max_geodes_within(32, &share, stats);
+    trace.push(Trace::leaf(format!("Blueprint {}", bp.id), geodes));
+    product = Best {
+      value: product.value * geodes.value as usize,
+      optimal: product.optimal && geodes.optimal,
+    };
+  }
+
+  trace.push(Trace::leaf("product", product));
+  (product, trace)
+}
+
+/// Geodes opened with every blueprint in each part.
+pub fn explain(input: &str) -> Trace {
+  let blueprints = parse(input);
+  let cancellation = Cancellation::new();
+
+  Trace::new("Day 19").with_children([
+    Trace::new("Part 1")
+      .with_children(quality_levels(&blueprints, &cancellation, &mut BoundStats::start()).1),
+    Trace::new("Part 2")
+      .with_children(geodes_product(&blueprints, &cancellation, &mut BoundStats::start()).1),
+  ])
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_INPUT: &str = include_str!("sample");
+
+  #[test]
+  fn test_solve_part_1() {
+    let res = part_1(TEST_INPUT);
+    assert_eq!(res, 33);
+  }
+
+  #[test]
+  fn test_solve_part_2() {
+    let res = part_2(TEST_INPUT);
+    assert_eq!(res, 3472)
+  }
+
+  #[test]
+  fn test_explain() {
+    let trace = explain(TEST_INPUT);
+    assert_eq!(
+      trace.children[0].children,
+      [
+        Trace::new("Blueprint 1")
+          .with_children([Trace::leaf("geodes", 9), Trace::leaf("quality level", 9)]),
+        Trace::new("Blueprint 2")
+          .with_children([Trace::leaf("geodes", 12), Trace::leaf("quality level", 24)]),
+        Trace::leaf("sum", 33),
+      ]
+    );
+    assert_eq!(
+      trace.children[1].children,
+      [
+        Trace::leaf("Blueprint 1", 56),
+        Trace::leaf("Blueprint 2", 62),
+        Trace::leaf("product", 3472),
+      ]
+    );
+  }
+}
+
+#[cfg(test)]
+mod properties {
+  use super::*;
+  use proptest::prelude::*;
+  use std::collections::HashMap;
+
+  /// Tries every choice in every minute, remembering already explored states.
+  fn naive_max_geodes(blueprint: &Blueprint, minutes: u32) -> u32 {
+    fn visit(
+      blueprint: &Blueprint,
+      remaining: u32,
+      ores: [u32; 4],
+      robots: [u32; 4],
+      cache: &mut HashMap<(u32, [u32; 4], [u32; 4]), u32>,
+    ) -> u32 {
+      if remaining == 0 {
+        return ores[3];
+      }
+      if let Some(&geodes) = cache.get(&(remaining, ores, robots)) {
+        return geodes;
+      }
+      let mined = |ores: [u32; 4]| [0, 1, 2, 3].map(|o| ores[o] + robots[o]);
+
+      let mut best = visit(blueprint, remaining - 1, mined(ores), robots, cache);
+      for (robot, recipe) in blueprint.recipes.iter().enumerate() {
+        if (0..4).all(|o| ores[o] >= recipe[o]) {
+          let ores = mined([0, 1, 2, 3].map(|o| ores[o] - recipe[o]));
+          let mut robots = robots;
+          robots[robot] += 1;
+          best = best.max(visit(blueprint, remaining - 1, ores, robots, cache));
+        }
+      }
+      cache.insert((remaining, ores, robots), best);
+      best
+    }
+
+    visit(
+      blueprint,
+      minutes,
+      [0; 4],
+      [1, 0, 0, 0],
+      &mut HashMap::new(),
+    )
+  }
+
+  proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn max_geodes_matches_naive(
+      (ore_ore, clay_ore, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian) in
+        (1u32..5, 1u32..5, 1u32..5, 1u32..8, 1u32..5, 1u32..8),
+      minutes in 1u32..16,
+    ) {
+      let input = format!(
+        "Blueprint 1: Each ore robot costs {ore_ore} ore. Each clay robot costs {clay_ore} ore. \
+         Each obsidian robot costs {obsidian_ore} ore and {obsidian_clay} clay. \
+         Each geode robot costs {geode_ore} ore and {geode_obsidian} obsidian."
+      );
+      let blueprint = Blueprint::from_str(&input).unwrap();
+      prop_assert_eq!(blueprint.max_geodes(minutes), naive_max_geodes(&blueprint, minutes));
+    }
+  }
+}
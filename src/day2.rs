@@ -0,0 +1,150 @@
+//! Day 2: Rock Paper Scissors
+
+use std::{cmp::Ordering, convert::Infallible, str::FromStr};
+
+/// How a round has to end, as dictated by the second column in part 2.
+#[derive(Debug)]
+pub enum Outcome {
+  Lose = 1,
+  Draw,
+  Win,
+}
+
+impl FromStr for Outcome {
+  type Err = Infallible;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "X" => Ok(Self::Lose),
+      "Y" => Ok(Self::Draw),
+      "Z" => Ok(Self::Win),
+      _ => panic!(),
+    }
+  }
+}
+
+/// A shape played in a round, worth its discriminant in points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hand {
+  Rock = 1,
+  Paper,
+  Scissors,
+}
+
+impl Hand {
+  /// Points for playing `self` against `other`.
+  pub fn result(&self, other: &Self) -> u32 {
+    match self.partial_cmp(other) {
+      Some(Ordering::Equal) => 3,
+      Some(Ordering::Greater) => 6,
+      Some(Ordering::Less) => 0,
+      None => panic!(),
+    }
+  }
+
+  /// The shape to play against `self` to reach `outcome`.
+  pub fn move_decider(&self, outcome: Outcome) -> Self {
+    match (self, outcome) {
+      (Hand::Rock, Outcome::Lose) => Self::Scissors,
+      (Hand::Rock, Outcome::Win) => Self::Paper,
+      (Hand::Paper, Outcome::Lose) => Self::Rock,
+      (Hand::Paper, Outcome::Win) => Self::Scissors,
+      (Hand::Scissors, Outcome::Lose) => Self::Paper,
+      (Hand::Scissors, Outcome::Win) => Self::Rock,
+      (Hand::Rock, Outcome::Draw) => Self::Rock,
+      (Hand::Paper, Outcome::Draw) => Self::Paper,
+      (Hand::Scissors, Outcome::Draw) => Self::Scissors,
+    }
+  }
+}
+
+impl FromStr for Hand {
+  type Err = Infallible;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "A" | "X" => Ok(Self::Rock),
+      "B" | "Y" => Ok(Self::Paper),
+      "C" | "Z" => Ok(Self::Scissors),
+      _ => panic!(),
+    }
+  }
+}
+
+impl PartialOrd for Hand {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    match (self, other) {
+      (Self::Rock, Self::Scissors) => Some(Ordering::Greater),
+      (Self::Scissors, Self::Rock) => Some(Ordering::Less),
+      _ => (*self as u8).partial_cmp(&(*other as u8)),
+    }
+  }
+}
+
+/// Rounds read as both players' shapes.
+pub fn parse_part_1(input: &str) -> impl Iterator<Item = (Hand, Hand)> + '_ {
+  input.lines().map(|round| {
+    round
+      .split_once(" ")
+      .map(|(player_one, player_two)| {
+        (
+          player_one.parse::<Hand>().unwrap(),
+          player_two.parse::<Hand>().unwrap(),
+        )
+      })
+      .unwrap()
+  })
+}
+
+/// Rounds read as the opponent's shape and the outcome to reach.
+pub fn parse_part_2(input: &str) -> impl Iterator<Item = (Hand, Outcome)> + '_ {
+  input.lines().map(|round| {
+    round
+      .split_once(" ")
+      .map(|(hand, outcome)| {
+        (
+          hand.parse::<Hand>().unwrap(),
+          outcome.parse::<Outcome>().unwrap(),
+        )
+      })
+      .unwrap()
+  })
+}
+
+/// Total score when the second column is the shape to play.
+pub fn part_1(input: &str) -> u32 {
+  parse_part_1(input)
+    .map(|(p1, p2)| p2.result(&p1) + p2 as u32)
+    .sum()
+}
+
+/// Total score when the second column is the outcome to reach.
+pub fn part_2(input: &str) -> u32 {
+  parse_part_2(input)
+    .map(|(player_one, outcome)| {
+      let player_two = player_one.move_decider(outcome);
+      player_two.result(&player_one) + player_two as u32
+    })
+    .sum()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_INPUT: &str = "A Y
+B X
+C Z";
+
+  #[test]
+  fn test_solve_part_1() {
+    let res = part_1(TEST_INPUT);
+    assert_eq!(res, 15);
+  }
+
+  #[test]
+  fn test_solve_part_2() {
+    let res = part_2(TEST_INPUT);
+    assert_eq!(res, 12)
+  }
+}
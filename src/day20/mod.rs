@@ -0,0 +1,160 @@
+//! Day 20: Grove Positioning System
+
+#![allow(unused_variables)]
+
+use std::collections::VecDeque;
+
+use serde::Deserialize;
+
+use crate::{explain::Trace, math::ModInt, scaling::Rng};
+
+const DECRYPTION_KEY: &str = include_str!("decryption_key");
+
+/// The key the numbers are multiplied by in part 2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+  pub decryption_key: i64,
+}
+
+impl Default for Params {
+  fn default() -> Self {
+    Self {
+      decryption_key: DECRYPTION_KEY.trim().parse().unwrap(),
+    }
+  }
+}
+
+/// The encrypted file and the current order of its numbers.
+#[derive(Debug)]
+pub struct Decryption {
+  data: Vec<i64>,
+  positions: VecDeque<usize>,
+}
+
+impl Decryption {
+  /// Applies the decryption key to every number of the file.
+  pub fn new(data: &[i64], decryption_key: &i64) -> Self {
+    let data = data.iter().map(|&x| x * decryption_key).collect::<Vec<_>>();
+    let positions = (0..data.len()).collect::<VecDeque<usize>>();
+    Self { data, positions }
+  }
+
+  /// Moves every number by its value, `iterations` times in the original order.
+  pub fn mix(&mut self, iterations: usize) {
+    // A number is moved among the others, so it wraps around one position early.
+    let modulus = self.positions.len() as u64 - 1;
+    (0..iterations).for_each(|_| {
+      for (i, &x) in self.data.iter().enumerate() {
+        let current_position = self.positions.iter().position(|&y| y == i).unwrap();
+        self.positions.remove(current_position);
+        let new_index = ModInt::new(current_position as i64, modulus) + ModInt::new(x, modulus);
+        self.positions.insert(new_index.value() as usize, i)
+      }
+    });
+  }
+
+  /// The 1000th, 2000th and 3000th numbers after the value 0.
+  pub fn coordinates(&self) -> Vec<i64> {
+    let idx_0_old = self.data.iter().position(|&x| x == 0).unwrap();
+    let idx_0_new = self.positions.iter().position(|&x| x == idx_0_old).unwrap();
+
+    [1000, 2000, 3000]
+      .iter()
+      .map(|i| self.data[self.positions[(idx_0_new + i) % self.positions.len()]])
+      .collect()
+  }
+}
+
+/// Parses one number per line.
+pub fn parse(input: &str) -> Vec<i64> {
+  input.trim().lines().map(|l| l.parse().unwrap()).collect()
+}
+
+/// Sum of the grove coordinates after mixing once.
+pub fn part_1(input: &str) -> i64 {
+  let data = parse(input);
+  let mut encryption = Decryption::new(&data, &1);
+
+  encryption.mix(1);
+  encryption.coordinates().iter().sum()
+}
+
+/// Sum of the grove coordinates after decrypting and mixing ten times.
+pub fn part_2(input: &str, decryption_key: i64) -> i64 {
+  let data = parse(input);
+  let mut encryption = Decryption::new(&data, &decryption_key);
+
+  encryption.mix(10);
+  encryption.coordinates().iter().sum()
+}
+
+/// The three grove coordinates found in each part.
+pub fn explain(input: &str, decryption_key: i64) -> Trace {
+  let data = parse(input);
+
+  let coordinates = |label: &str, decryption_key: i64, iterations: usize| {
+    let mut encryption = Decryption::new(&data, &decryption_key);
+    encryption.mix(iterations);
+
+    Trace::new(label).with_children(
+      [1000, 2000, 3000]
+        .into_iter()
+        .zip(encryption.coordinates())
+        .map(|(offset, coordinate)| Trace::leaf(format!("{offset}th"), coordinate)),
+    )
+  };
+
+  Trace::new("Day 20").with_children([
+    coordinates("Part 1", 1, 1),
+    coordinates("Part 2", decryption_key, 10),
+  ])
+}
+
+/// An encrypted file of `size` random numbers, exactly one of them 0.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+  let zero = rng.below(size as u64) as usize;
+  (0..size)
+    .map(|idx| match idx == zero {
+      true => 0,
+      false => match rng.below(20_000) as i64 - 10_000 {
+        0 => 10_000,
+        number => number,
+      },
+    })
+    .map(|number| number.to_string())
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_INPUT: &str = include_str!("sample");
+
+  #[test]
+  fn test_solve_part_1() {
+    let res = part_1(TEST_INPUT);
+    assert_eq!(res, 3);
+  }
+
+  #[test]
+  fn test_solve_part_2() {
+    let res = part_2(TEST_INPUT, Params::default().decryption_key);
+    assert_eq!(res, 1623178306)
+  }
+
+  #[test]
+  fn test_explain() {
+    let trace = explain(TEST_INPUT, Params::default().decryption_key);
+    assert_eq!(
+      trace.children[0].children,
+      [
+        Trace::leaf("1000th", 4),
+        Trace::leaf("2000th", -3),
+        Trace::leaf("3000th", 2),
+      ]
+    );
+  }
+}
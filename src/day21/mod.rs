@@ -0,0 +1,267 @@
+//! Day 21: Monkey Math
+
+use std::{
+  cmp::Ordering,
+  collections::{BTreeMap, HashMap},
+};
+
+/// What a monkey yells, either a number or an operation on two other monkeys.
+#[derive(Debug, Clone)]
+pub enum Yell<'a> {
+  Number(i128),
+  Add(&'a str, &'a str),
+  Sub(&'a str, &'a str),
+  Mul(&'a str, &'a str),
+  Div(&'a str, &'a str),
+}
+
+impl<'a> Yell<'a> {
+  /// The two monkeys an operation works on, `None` for a number.
+  pub fn operands(&self) -> Option<(&'a str, &'a str)> {
+    match *self {
+      Yell::Number(_) => None,
+      Yell::Add(lhs, rhs) | Yell::Sub(lhs, rhs) | Yell::Mul(lhs, rhs) | Yell::Div(lhs, rhs) => {
+        Some((lhs, rhs))
+      }
+    }
+  }
+}
+
+/// Parses every monkey's job, keyed by its name.
+pub fn parse(input: &str) -> BTreeMap<&str, Yell<'_>> {
+  input
+    .trim()
+    .lines()
+    .fold(BTreeMap::new(), |mut actions, l| {
+      let (id, yell) = l.split_once(": ").unwrap();
+      let split = yell.split_whitespace().collect::<Vec<_>>();
+      let yell = match split[..] {
+        [lhs, op, rhs] => match op.trim() {
+          "+" => Yell::Add(lhs, rhs),
+          "-" => Yell::Sub(lhs, rhs),
+          "*" => Yell::Mul(lhs, rhs),
+          "/" => Yell::Div(lhs, rhs),
+          _ => unreachable!(),
+        },
+        [num] => Yell::Number(num.parse().unwrap()),
+        _ => unreachable!(),
+      };
+
+      actions.insert(id, yell);
+      actions
+    })
+}
+
+/// Number yelled by `monkey`, caching every monkey evaluated along the way.
+pub fn evaluate<'a>(
+  monkey: &'a str,
+  yells: &'a BTreeMap<&'a str, Yell>,
+  cache: &mut HashMap<&'a str, i128>,
+) -> i128 {
+  if let Some(val) = cache.get(monkey) {
+    return *val;
+  };
+
+  let val = match yells.get(monkey).unwrap() {
+    Yell::Number(val) => *val,
+    Yell::Add(lhs, rhs) => evaluate(lhs, yells, cache) + evaluate(rhs, yells, cache),
+    Yell::Sub(lhs, rhs) => evaluate(lhs, yells, cache) - evaluate(rhs, yells, cache),
+    Yell::Mul(lhs, rhs) => evaluate(lhs, yells, cache) * evaluate(rhs, yells, cache),
+    Yell::Div(lhs, rhs) => evaluate(lhs, yells, cache) / evaluate(rhs, yells, cache),
+  };
+
+  cache.insert(monkey, val);
+  val
+}
+
+/// Number yelled by `root`.
+pub fn part_1(input: &str) -> i128 {
+  let yells = parse(input);
+  let mut cache = HashMap::new();
+  evaluate("root", &yells, &mut cache)
+}
+
+fn binary_search<F>(mut lo: i128, mut hi: i128, mut cmp: F) -> Option<i128>
+where
+  F: FnMut(i128) -> Ordering,
+{
+  while lo != hi {
+    let mid = (lo + hi) / 2;
+    match cmp(mid) {
+      Ordering::Equal => hi = mid,
+      Ordering::Less => hi = mid - 1,
+      Ordering::Greater => lo = mid + 1,
+    }
+  }
+  match cmp(lo) {
+    Ordering::Equal => Some(lo),
+    Ordering::Less => None,
+    Ordering::Greater => None,
+  }
+}
+
+/// Number `humn` has to yell for both operands of `root` to be equal.
+pub fn part_2(input: &str) -> i128 {
+  let mut yells = parse(input);
+  let mut cache = HashMap::new();
+
+  let (lhs, rhs) = yells["root"].operands().unwrap();
+
+  let start = evaluate(lhs, &yells, &mut cache).cmp(&evaluate(rhs, &yells, &mut cache));
+  binary_search(0, i64::MAX as i128, move |x| {
+    let mut cache = HashMap::new();
+    yells.insert("humn", Yell::Number(x));
+    let next = evaluate(lhs, &yells, &mut cache).cmp(&evaluate(rhs, &yells, &mut cache));
+    match next {
+      Ordering::Equal => Ordering::Equal,
+      next => match start == next {
+        true => Ordering::Greater,
+        false => Ordering::Less,
+      },
+    }
+  })
+  .unwrap()
+}
+
+fn depends_on_human(monkey: &str, yells: &BTreeMap<&str, Yell>) -> bool {
+  monkey == "humn"
+    || yells[monkey]
+      .operands()
+      .is_some_and(|(lhs, rhs)| depends_on_human(lhs, yells) || depends_on_human(rhs, yells))
+}
+
+/// Same as [`part_2`], but undoing every operation on the way from `root` down to `humn`
+/// instead of searching. Assumes `humn` appears only once, as it does in the puzzle.
+pub fn part_2_symbolic(input: &str) -> i128 {
+  let yells = parse(input);
+  let mut cache = HashMap::new();
+
+  let (lhs, rhs) = yells["root"].operands().unwrap();
+  let (mut monkey, mut target) = match depends_on_human(lhs, &yells) {
+    true => (lhs, evaluate(rhs, &yells, &mut cache)),
+    false => (rhs, evaluate(lhs, &yells, &mut cache)),
+  };
+
+  while monkey != "humn" {
+    let (lhs, rhs) = yells[monkey].operands().unwrap();
+    let human_on_left = depends_on_human(lhs, &yells);
+    let (next, other) = match human_on_left {
+      true => (lhs, evaluate(rhs, &yells, &mut cache)),
+      false => (rhs, evaluate(lhs, &yells, &mut cache)),
+    };
+    target = match (&yells[monkey], human_on_left) {
+      (Yell::Add(..), _) => target - other,
+      (Yell::Sub(..), true) => target + other,
+      (Yell::Sub(..), false) => other - target,
+      (Yell::Mul(..), _) => target / other,
+      (Yell::Div(..), true) => target * other,
+      (Yell::Div(..), false) => other / target,
+      (Yell::Number(_), _) => unreachable!(),
+    };
+    monkey = next;
+  }
+
+  target
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_INPUT: &str = include_str!("sample");
+
+  #[test]
+  fn test_solve_part_1() {
+    let res = part_1(TEST_INPUT);
+    assert_eq!(res, 152);
+  }
+
+  #[test]
+  fn test_solve_part_2() {
+    let res = part_2(TEST_INPUT);
+    assert_eq!(res, 301);
+    assert_eq!(part_2_symbolic(TEST_INPUT), 301);
+  }
+}
+
+#[cfg(test)]
+mod properties {
+  use super::*;
+  use proptest::prelude::*;
+
+  /// One step on the path from `root` down to `humn`, the other operand being a constant.
+  #[derive(Debug, Clone, Copy)]
+  enum Step {
+    AddRhs(i128),
+    SubRhs(i128),
+    SubLhs(i128),
+    MulRhs(i128),
+  }
+
+  fn render(steps: &[Step], constant: i128) -> String {
+    let mut lines = vec![format!("root: m0 + c{}", steps.len())];
+    lines.push(format!("c{}: {constant}", steps.len()));
+    for (i, step) in steps.iter().enumerate() {
+      let (next, c) = (
+        if i + 1 == steps.len() {
+          "humn".to_string()
+        } else {
+          format!("m{}", i + 1)
+        },
+        format!("c{i}"),
+      );
+      let (line, value) = match step {
+        Step::AddRhs(value) => (format!("m{i}: {next} + {c}"), value),
+        Step::SubRhs(value) => (format!("m{i}: {next} - {c}"), value),
+        Step::SubLhs(value) => (format!("m{i}: {c} - {next}"), value),
+        Step::MulRhs(value) => (format!("m{i}: {next} * {c}"), value),
+      };
+      lines.push(line);
+      lines.push(format!("{c}: {value}"));
+    }
+    lines.push("humn: 0".to_string());
+    lines.join("\n")
+  }
+
+  fn apply(steps: &[Step], humn: i128) -> i128 {
+    steps.iter().rev().fold(humn, |value, step| match step {
+      Step::AddRhs(c) => value + c,
+      Step::SubRhs(c) => value - c,
+      Step::SubLhs(c) => c - value,
+      Step::MulRhs(c) => value * c,
+    })
+  }
+
+  /// Inverts every step from the top down, which is exact because every step is injective.
+  fn naive_part_2(steps: &[Step], constant: i128) -> i128 {
+    steps.iter().fold(constant, |value, step| match step {
+      Step::AddRhs(c) => value - c,
+      Step::SubRhs(c) => value + c,
+      Step::SubLhs(c) => c - value,
+      Step::MulRhs(c) => value / c,
+    })
+  }
+
+  fn steps() -> impl Strategy<Value = Vec<Step>> {
+    prop::collection::vec(
+      prop_oneof![
+        (-50i128..50).prop_map(Step::AddRhs),
+        (-50i128..50).prop_map(Step::SubRhs),
+        (-50i128..50).prop_map(Step::SubLhs),
+        (1i128..20).prop_map(Step::MulRhs),
+      ],
+      1..7,
+    )
+  }
+
+  proptest! {
+    #[test]
+    fn part_2_matches_naive(steps in steps(), humn in 0i128..1_000_000) {
+      let constant = apply(&steps, humn);
+      let input = render(&steps, constant);
+      prop_assert_eq!(naive_part_2(&steps, constant), humn);
+      prop_assert_eq!(part_2(&input), humn, "{}", input);
+      prop_assert_eq!(part_2_symbolic(&input), humn, "{}", input);
+    }
+  }
+}
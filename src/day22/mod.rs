@@ -0,0 +1,355 @@
+//! Day 22: Monkey Map
+
+use std::{fmt::Display, str::FromStr};
+
+use itertools::Itertools;
+use serde::Deserialize;
+
+use crate::tile::{Tile, UnknownTile};
+
+/// Shape of the cube the map folds into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+  /// Side length of a face, 4 for the sample.
+  pub face_size: usize,
+}
+
+impl Default for Params {
+  fn default() -> Self {
+    Self { face_size: 50 }
+  }
+}
+
+/// The direction someone on the map is facing.
+#[derive(Debug, Clone, Copy, Tile)]
+pub enum Direction {
+  #[tile('>')]
+  Right,
+  #[tile('<')]
+  Left,
+  #[tile('^')]
+  Up,
+  #[tile('v')]
+  Down,
+}
+
+impl From<Direction> for usize {
+  fn from(value: Direction) -> Self {
+    match value {
+      Direction::Right => 0,
+      Direction::Down => 1,
+      Direction::Left => 2,
+      Direction::Up => 3,
+    }
+  }
+}
+
+impl From<usize> for Direction {
+  fn from(value: usize) -> Self {
+    match value {
+      0 => Direction::Right,
+      1 => Direction::Down,
+      2 => Direction::Left,
+      3 => Direction::Up,
+      _ => unreachable!(),
+    }
+  }
+}
+
+impl Direction {
+  fn len() -> usize {
+    4
+  }
+
+  fn calculate_new_direction(&self, other: &Instruction) -> Direction {
+    let value = match other {
+      Instruction::Right => (usize::from(*self) + 1) % Direction::len(),
+      Instruction::Left => (usize::from(*self) + 3) % Direction::len(),
+      _ => usize::from(*self),
+    };
+    Direction::from(value)
+  }
+}
+
+/// A tile of the map, `Void` being outside of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Tile)]
+pub enum Tile {
+  #[tile('.')]
+  Free,
+  #[tile('#')]
+  Blocked,
+  #[tile(' ')]
+  Void,
+}
+
+/// A step of the path description.
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction {
+  Right,
+  Left,
+  Move(usize),
+}
+
+/// The map and the position and facing of the one walking on it.
+#[derive(Debug)]
+pub struct Cove {
+  tile_matrix: Vec<Vec<Tile>>,
+  current_direction: Direction,
+  current_position: [usize; 2],
+  moves: Vec<([usize; 2], Direction)>,
+}
+
+impl FromStr for Cove {
+  type Err = UnknownTile;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let input = s.trim_end();
+
+    let rows = input.lines().count();
+    let cols = input.lines().map(|row| row.len()).max().unwrap();
+
+    let mut tile_matrix = vec![vec![Tile::Void; cols]; rows];
+    for (y, line) in input.split('\n').enumerate() {
+      for (x, c) in line.chars().enumerate() {
+        tile_matrix[y][x] = Tile::try_from(c)?;
+      }
+    }
+
+    let current_position = [
+      tile_matrix[0]
+        .iter()
+        .position(|&t| t == Tile::Free)
+        .unwrap(),
+      0,
+    ];
+
+    let current_direction = Direction::Right;
+
+    Ok(Cove {
+      tile_matrix,
+      current_direction,
+      current_position,
+      moves: vec![(current_position, current_direction)],
+    })
+  }
+}
+
+impl Display for Cove {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let mut display = self
+      .tile_matrix
+      .iter()
+      .map(|row| row.iter().map(Tile::to_string).collect::<Vec<_>>())
+      .collect::<Vec<_>>();
+
+    for ([x, y], direction) in self.moves.iter() {
+      display[*y][*x] = direction.to_string()
+    }
+
+    for row in display {
+      for tile in row {
+        write!(f, "{}", tile)?;
+      }
+      writeln!(f)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl Cove {
+  /// Final password derived from the current row, column and facing.
+  pub fn calculate_password(&self) -> usize {
+    1000 * (self.current_position[1] + 1)
+      + 4 * (self.current_position[0] + 1)
+      + usize::from(self.current_direction)
+  }
+
+  fn calculate_new_position(&self, direction: &Direction, [x, y]: [usize; 2]) -> [usize; 2] {
+    let (x_dim, y_dim) = (self.tile_matrix[y].len(), self.tile_matrix.len());
+    match direction {
+      Direction::Right => [(x + 1) % x_dim, y],
+      Direction::Left => [(x + x_dim - 1) % x_dim, y],
+      Direction::Up => [x, (y + y_dim - 1) % y_dim],
+      Direction::Down => [x, (y + 1) % y_dim],
+    }
+  }
+
+  fn change_direction(&mut self, instruction: &Instruction) {
+    self.current_direction = self.current_direction.calculate_new_direction(instruction);
+    self
+      .moves
+      .push((self.current_position, self.current_direction))
+  }
+
+  fn current_direction(&mut self, new_direction: Direction) {
+    self.current_direction = new_direction
+  }
+
+  fn current_position(&mut self, [x, y]: [usize; 2]) {
+    self.current_position = [x, y];
+    self
+      .moves
+      .push((self.current_position, self.current_direction))
+  }
+
+  fn change_position(&mut self, amount: usize, wrap: impl Fn(&Self) -> ([usize; 2], Direction)) {
+    for _ in 0..amount {
+      let [x, y] = self.calculate_new_position(&self.current_direction, self.current_position);
+      match self
+        .tile_matrix
+        .get(y)
+        .and_then(|row| row.get(x))
+        .unwrap_or(&Tile::Void)
+      {
+        Tile::Free => self.current_position([x, y]),
+        Tile::Blocked => break,
+        Tile::Void => {
+          let ([new_x, new_y], direction) = wrap(self);
+          if self.tile_matrix[new_y][new_x] == Tile::Blocked {
+            break;
+          }
+          self.current_position([new_x, new_y]);
+          self.current_direction(direction);
+        }
+      }
+    }
+  }
+
+  /// Wraps around the edge of a cube with faces of size `n`, folded like the puzzle input.
+  pub fn wrap_cube(&self, n: usize) -> ([usize; 2], Direction) {
+    let [x, y] = self.current_position;
+
+    let (qx, qy, new_direction) = match (x / n, y / n, self.current_direction) {
+      (1, 0, Direction::Up) => (0, 3, Direction::Right),
+      (1, 0, Direction::Left) => (0, 2, Direction::Right),
+      (2, 0, Direction::Up) => (0, 3, Direction::Up),
+      (2, 0, Direction::Right) => (1, 2, Direction::Left),
+      (2, 0, Direction::Down) => (1, 1, Direction::Left),
+      (1, 1, Direction::Right) => (2, 0, Direction::Up),
+      (1, 1, Direction::Left) => (0, 2, Direction::Down),
+      (0, 2, Direction::Up) => (1, 1, Direction::Right),
+      (0, 2, Direction::Left) => (1, 0, Direction::Right),
+      (1, 2, Direction::Right) => (2, 0, Direction::Left),
+      (1, 2, Direction::Down) => (0, 3, Direction::Left),
+      (0, 3, Direction::Right) => (1, 2, Direction::Up),
+      (0, 3, Direction::Down) => (2, 0, Direction::Down),
+      (0, 3, Direction::Left) => (1, 0, Direction::Down),
+      (face_x, face_y, direction) => {
+        unreachable!("no face at ({face_x}, {face_y}) leading {direction:?}")
+      }
+    };
+
+    let (dx, dy) = (x % n, y % n);
+    let i = match self.current_direction {
+      Direction::Right => dy,
+      Direction::Left => n - 1 - dy,
+      Direction::Up => dx,
+      Direction::Down => n - 1 - dx,
+    };
+
+    let (nx, ny) = match new_direction {
+      Direction::Right => (0, i),
+      Direction::Left => (n - 1, n - 1 - i),
+      Direction::Up => (i, n - 1),
+      Direction::Down => (n - 1 - i, 0),
+    };
+
+    ([qx * n + nx, qy * n + ny], new_direction)
+  }
+
+  /// Wraps around to the other side of the current row or column.
+  pub fn wrap(&self) -> ([usize; 2], Direction) {
+    let [mut x, mut y] =
+      self.calculate_new_position(&self.current_direction, self.current_position);
+    while *self
+      .tile_matrix
+      .get(y)
+      .and_then(|row| row.get(x))
+      .unwrap_or(&Tile::Void)
+      == Tile::Void
+    {
+      [x, y] = self.calculate_new_position(&self.current_direction, [x, y]);
+    }
+
+    ([x, y], self.current_direction)
+  }
+
+  /// Follows the path, wrapping around the edges of the map with `wrap`.
+  pub fn walk(
+    &mut self,
+    instructions: &[Instruction],
+    wrap: &impl Fn(&Self) -> ([usize; 2], Direction),
+  ) {
+    for instruction in instructions.iter() {
+      match instruction {
+        Instruction::Right | Instruction::Left => self.change_direction(instruction),
+        Instruction::Move(amount) => self.change_position(*amount, wrap),
+      }
+    }
+  }
+}
+
+/// Parses the map and the path description.
+pub fn parse(input: &str) -> (Cove, Vec<Instruction>) {
+  let input = input.trim_end();
+  let (map, instructions) = input.split_once("\n\n").unwrap();
+  let movements = instructions
+    .split_terminator(&['L', 'R'])
+    .map(|s| Instruction::Move(s.parse().unwrap()))
+    .collect::<Vec<_>>();
+  let directions = instructions
+    .chars()
+    .filter_map(|c| match c {
+      'L' => Some(Instruction::Left),
+      'R' => Some(Instruction::Right),
+      _ => None,
+    })
+    .collect::<Vec<_>>();
+
+  let mut instructions = vec![];
+  for iter in movements.into_iter().zip_longest(directions) {
+    match iter {
+      itertools::EitherOrBoth::Both(move_, direction) => {
+        instructions.extend_from_slice(&[move_, direction])
+      }
+      itertools::EitherOrBoth::Left(move_) => instructions.push(move_),
+      itertools::EitherOrBoth::Right(direction) => instructions.push(direction),
+    }
+  }
+  (Cove::from_str(map).unwrap(), instructions)
+}
+
+/// Password after walking the map as a flat board.
+pub fn part_1(input: &str) -> usize {
+  let (mut cove, instructions) = parse(input);
+  cove.walk(&instructions, &Cove::wrap);
+  cove.calculate_password()
+}
+
+/// Password after walking the map folded into a cube with faces of size `face_size`.
+pub fn part_2(input: &str, face_size: usize) -> usize {
+  let (mut cove, instructions) = parse(input);
+  cove.walk(&instructions, &|cove: &Cove| cove.wrap_cube(face_size));
+  cove.calculate_password()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_INPUT: &str = include_str!("sample");
+
+  #[test]
+  fn test_solve_part_1() {
+    let res = part_1(TEST_INPUT);
+    assert_eq!(res, 6032);
+  }
+
+  #[test]
+  fn test_solve_part_2() {
+    let res = part_2(TEST_INPUT, 4);
+    assert_eq!(res, 5031)
+  }
+}
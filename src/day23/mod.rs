@@ -0,0 +1,163 @@
+//! Day 23: Unstable Diffusion
+
+#![allow(unused_variables)]
+
+use std::{fmt::Display, hash::Hash, str::FromStr};
+
+use self::Direction::*;
+
+use strum::EnumIter;
+
+use crate::{
+  automaton::{Action, Automaton, Neighbourhood, Point, Rule, Sparse},
+  grid::SparseGrid,
+  tile::{Tile, UnknownTile},
+};
+
+/// A tile of the grove.
+#[derive(Debug, Clone, Copy, Tile)]
+pub enum Tile {
+  #[tile('#')]
+  Elve,
+  #[tile('.')]
+  Ground,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
+enum Direction {
+  NorthWest,
+  North,
+  NorthEast,
+  East,
+  SouthEast,
+  South,
+  SouthWest,
+  West,
+}
+
+impl From<Direction> for (isize, isize) {
+  fn from(value: Direction) -> Self {
+    match value {
+      Direction::North => (0, -1),
+      Direction::South => (0, 1),
+      Direction::West => (-1, 0),
+      Direction::East => (1, 0),
+      Direction::NorthEast => (1, -1),
+      Direction::NorthWest => (-1, -1),
+      Direction::SouthEast => (1, 1),
+      Direction::SouthWest => (-1, 1),
+    }
+  }
+}
+
+impl Direction {
+  fn calculate(&self, [x, y]: &[i32; 2]) -> [i32; 2] {
+    match self {
+      Direction::North => [*x, y - 1],
+      Direction::South => [*x, y + 1],
+      Direction::West => [x - 1, *y],
+      Direction::East => [x + 1, *y],
+      Direction::NorthEast => [x + 1, y - 1],
+      Direction::NorthWest => [x - 1, y - 1],
+      Direction::SouthEast => [x + 1, y + 1],
+      Direction::SouthWest => [x - 1, y + 1],
+    }
+  }
+}
+
+/// Positions of all elves.
+#[derive(Debug)]
+pub struct Grove(pub SparseGrid<Tile>);
+
+impl FromStr for Grove {
+  type Err = UnknownTile;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let mut grove = SparseGrid::new();
+    for (y, line) in s.lines().enumerate() {
+      for (x, c) in line.chars().enumerate() {
+        if let Tile::Elve = Tile::try_from(c)? {
+          grove.insert([x as i64, y as i64], Tile::Elve);
+        }
+      }
+    }
+    Ok(Self(grove))
+  }
+}
+
+impl Display for Grove {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.0.view(Tile::Ground))
+  }
+}
+
+/// Elves spreading out: an elf with company moves to the first free side, trying north,
+/// south, west and east starting one further every round.
+struct Elves;
+
+impl Rule for Elves {
+  type Cell = ();
+
+  fn act(&self, _: Point, _: &(), neighbourhood: &Neighbourhood<()>, round: usize) -> Action {
+    if neighbourhood.is_alone() {
+      return Action::Stay;
+    }
+    let sides = [
+      [North, NorthEast, NorthWest],
+      [South, SouthEast, SouthWest],
+      [West, NorthWest, SouthWest],
+      [East, NorthEast, SouthEast],
+    ];
+    (0..4)
+      .map(|i| sides[(round + i) % 4])
+      .find(|side| {
+        side
+          .iter()
+          .all(|d| !neighbourhood.is_occupied(d.calculate(&[0, 0])))
+      })
+      .map_or(Action::Stay, |[d, ..]| Action::Move(d.calculate(&[0, 0])))
+  }
+}
+
+impl Grove {
+  fn automaton(self) -> Automaton<Elves, Sparse<()>> {
+    let elves = self.0.points().map(|[x, y]| ([x as i32, y as i32], ()));
+    Automaton::new(Elves, elves.collect())
+  }
+}
+
+/// Empty ground tiles in the elves' bounding rectangle after ten rounds.
+pub fn part_1(input: &str) -> usize {
+  let mut automaton = Grove::from_str(input.trim()).unwrap().automaton();
+  automaton.run(10);
+
+  let elves = automaton.world.0.into_keys();
+  SparseGrid::from_iter(elves.map(|point| (point.map(i64::from), Tile::Elve))).empty_cells()
+}
+
+/// First round in which no elf moves.
+pub fn part_2(input: &str) -> usize {
+  Grove::from_str(input.trim())
+    .unwrap()
+    .automaton()
+    .run_until_stable()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_INPUT: &str = include_str!("sample");
+
+  #[test]
+  fn test_solve_part_1() {
+    let res = part_1(TEST_INPUT);
+    assert_eq!(res, 110);
+  }
+
+  #[test]
+  fn test_solve_part_2() {
+    let res = part_2(TEST_INPUT);
+    assert_eq!(res, 20)
+  }
+}
@@ -0,0 +1,182 @@
+//! Day 24: Blizzard Basin
+
+use std::{collections::HashSet, str::FromStr};
+
+use crate::{
+  math::lcm,
+  tile::{Tile, UnknownTile},
+};
+
+/// The direction a blizzard moves in.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Tile)]
+pub enum Direction {
+  #[tile('^')]
+  Up,
+  #[tile('v')]
+  Down,
+  #[tile('<')]
+  Left,
+  #[tile('>')]
+  Right,
+}
+
+impl From<Direction> for usize {
+  fn from(value: Direction) -> Self {
+    match value {
+      Direction::Up => 2,
+      Direction::Down => 3,
+      Direction::Left => 0,
+      Direction::Right => 1,
+    }
+  }
+}
+
+/// A tile of the valley.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Tile)]
+pub enum Tile {
+  #[tile('#')]
+  Wall,
+  #[tile('.')]
+  Free,
+  Direction(Direction),
+}
+
+/// The valley, its blizzards and every position the expedition could be in.
+#[derive(Debug)]
+pub struct Valley {
+  rows: usize,
+  cols: usize,
+  blizzards: Vec<(usize, usize, Direction)>,
+  positions: HashSet<(usize, usize)>,
+  minute: usize,
+}
+
+impl FromStr for Valley {
+  type Err = UnknownTile;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let rows = s.lines().count();
+    let cols = s.lines().next().unwrap().len();
+
+    let mut blizzards = vec![];
+    for (x, row) in s.lines().enumerate() {
+      for (y, c) in row.chars().enumerate() {
+        if let Tile::Direction(direction) = Tile::try_from(c)? {
+          blizzards.push((x, y, direction));
+        }
+      }
+    }
+
+    Ok(Self {
+      rows,
+      cols,
+      blizzards,
+      positions: HashSet::from([(0, 1)]),
+      minute: 0,
+    })
+  }
+}
+
+impl Valley {
+  /// Minutes after which every blizzard is back where it started.
+  pub fn period(&self) -> usize {
+    lcm([self.rows - 2, self.cols - 2])
+  }
+
+  /// Advances minute by minute until `target` can be reached.
+  ///
+  /// Panics if it cannot be: the blizzards repeat every [`Valley::period`] minutes, so after
+  /// that many minutes for each position nothing new can happen.
+  pub fn traverse(&mut self, target: &(usize, usize)) {
+    for minutes in 1..=self.rows * self.cols * self.period() {
+      for (y, x, d) in self.blizzards.iter_mut() {
+        match d {
+          Direction::Right => *x = if *x == self.cols - 2 { 1 } else { *x + 1 },
+          Direction::Left => *x = if *x == 1 { self.cols - 2 } else { *x - 1 },
+          Direction::Down => *y = if *y == self.rows - 2 { 1 } else { *y + 1 },
+          Direction::Up => *y = if *y == 1 { self.rows - 2 } else { *y - 1 },
+        }
+      }
+      let bpos = self
+        .blizzards
+        .iter()
+        .map(|&(x, y, _)| (x, y))
+        .collect::<HashSet<_>>();
+      let mut next_positions = HashSet::with_capacity(self.positions.len());
+      for &(x, y) in &self.positions {
+        for (dx, dy) in [(1, 0), (0, 1), (0, 0), (-1, 0), (0, -1)] {
+          if (x == 0 && dx == -1) || (x == self.rows - 1 && dx == 1) {
+            continue;
+          }
+          let (x, y) = (x as i32 + dx, y as i32 + dy);
+          if (x != 0 || y == 1)
+            && (x as usize != self.rows - 1 || y as usize == self.cols - 2)
+            && y != 0
+            && y as usize != self.cols - 1
+            && !bpos.contains(&(x as usize, y as usize))
+          {
+            next_positions.insert((x as usize, y as usize));
+          }
+        }
+      }
+      self.positions = next_positions;
+      if self.positions.contains(target) {
+        self.minute += minutes;
+        return;
+      }
+    }
+    panic!("{target:?} cannot be reached");
+  }
+}
+
+/// Fewest minutes needed to reach the goal.
+pub fn part_1(input: &str) -> usize {
+  let mut valley = Valley::from_str(input).unwrap();
+
+  valley.traverse(&(valley.rows - 1, valley.cols - 2));
+
+  valley.minute
+}
+/// Fewest minutes needed to reach the goal, go back for the snacks and reach it again.
+pub fn part_2(input: &str) -> usize {
+  let mut valley = Valley::from_str(input).unwrap();
+
+  valley.traverse(&(valley.rows - 1, valley.cols - 2));
+  valley.positions = HashSet::from_iter([(valley.rows - 1, valley.cols - 2)]);
+  valley.traverse(&(0, 1));
+  valley.positions = HashSet::from_iter([(0, 1)]);
+  valley.traverse(&(valley.rows - 1, valley.cols - 2));
+
+  valley.minute
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_INPUT: &str = include_str!("sample");
+
+  #[test]
+  fn test_solve_part_1() {
+    let res = part_1(TEST_INPUT);
+    assert_eq!(res, 18);
+  }
+
+  #[test]
+  #[ignore = "later"]
+  fn test_solve_part_2() {
+    let res = part_2(TEST_INPUT);
+    assert_eq!(res, 54)
+  }
+
+  #[test]
+  fn test_unknown_tile() {
+    assert_eq!(
+      Valley::from_str("#.###\n#.x.#\n###.#").unwrap_err(),
+      UnknownTile {
+        tile: "Tile",
+        found: 'x'
+      }
+    );
+  }
+}
@@ -0,0 +1,42 @@
+//! Day 25: Full of Hot Air
+
+#![allow(unused_variables)]
+
+use crate::checked;
+
+const DAY: u8 = 25;
+
+/// Decodes a SNAFU number.
+pub fn todec(s: &str) -> usize {
+  s.chars().fold(0, |n, d| {
+    let digit = "=-012".chars().position(|x| x == d).unwrap();
+    checked::sub(DAY, checked::add(DAY, checked::mul(DAY, n, 5), digit), 2)
+  })
+}
+
+/// Encodes a number as SNAFU.
+pub fn tosnafu(n: usize) -> String {
+  if n == 0 {
+    String::new()
+  } else {
+    tosnafu((n + 2) / 5) + ["0", "1", "2", "=", "-"][n % 5]
+  }
+}
+
+/// Sum of all fuel requirements, as a SNAFU number.
+pub fn part_1(input: &str) -> String {
+  tosnafu(checked::sum(DAY, input.lines().map(todec)))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_INPUT: &str = include_str!("sample");
+
+  #[test]
+  fn test_solve_part_1() {
+    let res = part_1(TEST_INPUT);
+    assert_eq!(res, String::from("2=-1=0"));
+  }
+}
@@ -0,0 +1,78 @@
+//! Day 3: Rucksack Reorganization
+
+use std::collections::HashSet;
+
+/// Splits every rucksack into the items of its two compartments.
+pub fn parse_part_1(input: &str) -> impl Iterator<Item = (HashSet<char>, HashSet<char>)> + '_ {
+  input
+    .lines()
+    .map(|rucksack| rucksack.split_at(rucksack.len() / 2))
+    .map(|(left_compartment, right_compartment)| {
+      (
+        left_compartment.chars().collect::<HashSet<char>>(),
+        right_compartment.chars().collect::<HashSet<char>>(),
+      )
+    })
+}
+
+/// Priority of an item, `a..=z` being 1 to 26 and `A..=Z` 27 to 52.
+pub fn priority(c: char) -> u32 {
+  match c.is_uppercase() {
+    true => c as u32 - 38,
+    false => c as u32 - 96,
+  }
+}
+
+/// Sum of the priorities of the items found in both compartments.
+pub fn part_1(input: &str) -> u32 {
+  let parsed = parse_part_1(input);
+
+  parsed
+    .flat_map(|(left_compartment, right_compartment)| {
+      left_compartment
+        .intersection(&right_compartment)
+        .cloned()
+        .collect::<Vec<_>>()
+    })
+    .map(priority)
+    .sum::<u32>()
+}
+
+/// Sum of the priorities of each group of three elves' badge.
+pub fn part_2(input: &str) -> u32 {
+  let parsed = input.lines().collect::<Vec<_>>();
+  let parsed = parsed.chunks_exact(3);
+  parsed
+    .map(|group| {
+      group[0]
+        .chars()
+        .find(|item| group[1].contains(*item) && group[2].contains(*item))
+        .unwrap()
+    })
+    .map(priority)
+    .sum::<u32>()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_INPUT: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+  #[test]
+  fn test_solve_part_1() {
+    let res = part_1(TEST_INPUT);
+    assert_eq!(res, 157);
+  }
+
+  #[test]
+  fn test_solve_part_2() {
+    let res = part_2(TEST_INPUT);
+    assert_eq!(res, 70)
+  }
+}
@@ -0,0 +1,216 @@
+//! Day 7: No Space Left On Device
+
+use std::{collections::HashMap, path::PathBuf};
+
+use crate::explain::Trace;
+
+use nom::{
+  branch::alt,
+  bytes::complete::{tag, take_while1},
+  combinator::{all_consuming, map},
+  sequence::{preceded, separated_pair},
+  Finish, IResult,
+};
+
+/// A line of `ls` output.
+#[derive(Debug)]
+pub enum Entry {
+  Dir(PathBuf),
+  File(u64, PathBuf),
+}
+
+/// A command typed after the `$` prompt.
+#[derive(Debug)]
+pub enum Command {
+  Cd(PathBuf),
+  Ls,
+}
+
+/// A single line of the terminal output.
+#[derive(Debug)]
+pub enum Operation {
+  Command(Command),
+  Entry(Entry),
+}
+
+fn parse_path(input: &str) -> IResult<&str, PathBuf> {
+  map(
+    take_while1(|c| "abcdefghijklmnopqrstuvwxyz./".contains(c)),
+    Into::into,
+  )(input)
+}
+
+fn parse_cd(input: &str) -> IResult<&str, Command> {
+  map(preceded(tag("cd "), parse_path), Command::Cd)(input)
+}
+
+fn parse_ls(input: &str) -> IResult<&str, Command> {
+  map(tag("ls"), |_| Command::Ls)(input)
+}
+
+fn parse_entry(input: &str) -> IResult<&str, Entry> {
+  let parse_file = map(
+    separated_pair(nom::character::complete::u64, tag(" "), parse_path),
+    |(size, path)| Entry::File(size, path),
+  );
+  let parse_dir = map(preceded(tag("dir "), parse_path), Entry::Dir);
+  alt((parse_file, parse_dir))(input)
+}
+
+fn parse_command(input: &str) -> IResult<&str, Command> {
+  let (input, _) = tag("$ ")(input)?;
+  alt((parse_ls, parse_cd))(input)
+}
+
+/// Parses a single line of the terminal output.
+pub fn parse_line(input: &str) -> IResult<&str, Operation> {
+  let (input, operation) = alt((
+    map(parse_command, Operation::Command),
+    map(parse_entry, Operation::Entry),
+  ))(input)?;
+  Ok((input, operation))
+}
+
+/// Total size of every directory, keyed by its absolute path.
+pub fn operations_to_directories(ops: &[Operation]) -> HashMap<PathBuf, u64> {
+  let mut history = vec![];
+  ops.iter().fold(HashMap::new(), |mut acc, operation| {
+    match operation {
+      Operation::Command(cmd) => match cmd {
+        Command::Cd(path) => match path.to_str().unwrap() {
+          ".." => {
+            history.pop();
+          }
+          "/" => {
+            history.clear();
+            history.push("/")
+          }
+          path => history.push(path),
+        },
+        Command::Ls => (),
+      },
+      Operation::Entry(entry) => match entry {
+        Entry::Dir(_) => (),
+        Entry::File(size, _) => {
+          for (idx, _) in history.iter().enumerate() {
+            let path = PathBuf::from_iter(&history[..=idx]);
+            *acc.entry(path).or_insert(0) += size
+          }
+        }
+      },
+    }
+    acc
+  })
+}
+
+/// Sum of the sizes of all directories of at most 100000.
+pub fn part_1(input: &str) -> u64 {
+  let parsed = input
+    .lines()
+    .map(|line| all_consuming(parse_line)(line).finish().unwrap().1)
+    .collect::<Vec<_>>();
+
+  let dirs = operations_to_directories(&parsed);
+
+  dirs.into_values().filter(|val| *val <= 100_000).sum()
+}
+
+/// Size of the smallest directory that frees up enough space for the update.
+pub fn part_2(input: &str) -> u64 {
+  let parsed = input
+    .lines()
+    .map(|line| all_consuming(parse_line)(line).finish().unwrap().1)
+    .collect::<Vec<_>>();
+
+  let dirs = operations_to_directories(&parsed);
+
+  let total = 70_000_000;
+  let needed = 30_000_000;
+  let used = dirs.get(&PathBuf::from("/")).unwrap();
+  let unused = total - used;
+
+  dirs
+    .into_values()
+    .filter(|size| unused + size >= needed)
+    .min()
+    .unwrap()
+}
+
+/// The size of every directory and the space the update still needs.
+pub fn explain(input: &str) -> Trace {
+  let parsed = input
+    .lines()
+    .map(|line| all_consuming(parse_line)(line).finish().unwrap().1)
+    .collect::<Vec<_>>();
+
+  let mut dirs = operations_to_directories(&parsed)
+    .into_iter()
+    .collect::<Vec<_>>();
+  dirs.sort();
+
+  let used = dirs[0].1;
+  Trace::new("Day 7").with_children([
+    Trace::new("directories").with_children(
+      dirs
+        .iter()
+        .map(|(path, size)| Trace::leaf(path.display().to_string(), size)),
+    ),
+    Trace::leaf("used", used),
+    Trace::leaf("to free", (used + 30_000_000).saturating_sub(70_000_000)),
+  ])
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_INPUT: &str = "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+
+  #[test]
+  fn test_solve_part_1() {
+    let res = part_1(TEST_INPUT);
+    assert_eq!(res, 95437);
+  }
+
+  #[test]
+  fn test_solve_part_2() {
+    let res = part_2(TEST_INPUT);
+    assert_eq!(res, 24933642)
+  }
+
+  #[test]
+  fn test_explain() {
+    let trace = explain(TEST_INPUT);
+    assert_eq!(
+      trace.children[0].children,
+      [
+        Trace::leaf("/", 48381165),
+        Trace::leaf("/a", 94853),
+        Trace::leaf("/a/e", 584),
+        Trace::leaf("/d", 24933642),
+      ]
+    );
+  }
+}
@@ -0,0 +1,142 @@
+//! Day 8: Treetop Tree House
+
+use crate::scaling::Rng;
+
+/// Tree heights, one row per line with the last line first.
+pub fn parse(input: &str) -> Vec<Vec<u32>> {
+  let y = input.lines().count();
+
+  input
+    .lines()
+    .rev()
+    .enumerate()
+    .fold(vec![vec![]; y], |mut forest, (y, line)| {
+      let tree_line = line
+        .chars()
+        .map(|height| height.to_digit(10).unwrap())
+        .collect::<Vec<_>>();
+      forest[y] = tree_line;
+      forest
+    })
+}
+
+/// Whether the tree at `(x, y)` can be seen from outside the grid.
+pub fn is_visible(height: &u32, (x, y): (usize, usize), trees: &[Vec<u32>]) -> bool {
+  if x == 0 || y == 0 || x == trees.len() - 1 || y == trees[x].len() - 1 {
+    return true;
+  };
+
+  let visible_left = (0..x).rev().all(|position| trees[y][position] < *height);
+  let visible_right = (x + 1..trees[y].len()).all(|position| trees[y][position] < *height);
+  let visible_top = (y + 1..trees[x].len()).all(|position| trees[position][x] < *height);
+  let visible_bottom = (0..y).rev().all(|position| trees[position][x] < *height);
+
+  visible_left || visible_right || visible_top || visible_bottom
+}
+
+/// Product of the viewing distances in all four directions.
+pub fn scenic_score(height: &u32, (x, y): (usize, usize), trees: &[Vec<u32>]) -> usize {
+  let mut score_left = 0;
+  for tree in trees[y][..x].iter().rev() {
+    score_left += 1;
+    if tree >= height {
+      break;
+    }
+  }
+
+  let mut score_right = 0;
+  for tree in &trees[y][x + 1..] {
+    score_right += 1;
+    if tree >= height {
+      break;
+    }
+  }
+
+  let mut score_top = 0;
+  for row in &trees[y + 1..trees[x].len()] {
+    score_top += 1;
+    if row[x] >= *height {
+      break;
+    }
+  }
+
+  let mut score_bottom = 0;
+  for position in (0..y).rev() {
+    score_bottom += 1;
+    if trees[position][x] >= *height {
+      break;
+    }
+  }
+  score_left * score_right * score_top * score_bottom
+}
+
+/// Number of trees visible from outside the grid.
+pub fn part_1(input: &str) -> usize {
+  let forest = parse(input);
+
+  forest
+    .iter()
+    .enumerate()
+    .map(|(y, tree_line)| {
+      tree_line
+        .iter()
+        .enumerate()
+        .filter(|(x, height)| is_visible(height, (*x, y), &forest))
+        .count()
+    })
+    .sum()
+}
+
+/// Highest scenic score of any tree.
+pub fn part_2(input: &str) -> usize {
+  let forest = parse(input);
+
+  forest
+    .iter()
+    .enumerate()
+    .map(|(y, tree_line)| {
+      tree_line
+        .iter()
+        .enumerate()
+        .map(|(x, height)| scenic_score(height, (x, y), &forest))
+        .max()
+        .unwrap()
+    })
+    .max()
+    .unwrap()
+}
+
+/// A square forest of `size` trees per side with random heights.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+  (0..size)
+    .map(|_| {
+      (0..size)
+        .map(|_| char::from_digit(rng.below(10) as u32, 10).unwrap())
+        .collect::<String>()
+    })
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_INPUT: &str = "30373
+25512
+65332
+33549
+35390";
+
+  #[test]
+  fn test_solve_part_1() {
+    let res = part_1(TEST_INPUT);
+    assert_eq!(res, 21);
+  }
+
+  #[test]
+  fn test_solve_part_2() {
+    let res = part_2(TEST_INPUT);
+    assert_eq!(res, 8)
+  }
+}
@@ -0,0 +1,128 @@
+use std::fmt::Display;
+
+use serde::Serialize;
+
+/// Intermediate results of a solver, as a tree of labelled values.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Trace {
+  pub label: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub value: Option<String>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub children: Vec<Trace>,
+}
+
+impl Trace {
+  /// A node without a value, to which children are added.
+  pub fn new(label: impl Into<String>) -> Self {
+    Self {
+      label: label.into(),
+      ..Default::default()
+    }
+  }
+
+  /// A node holding a single value.
+  pub fn leaf(label: impl Into<String>, value: impl Display) -> Self {
+    Self::new(label).with_value(value)
+  }
+
+  pub fn with_value(mut self, value: impl Display) -> Self {
+    self.value = Some(value.to_string());
+    self
+  }
+
+  pub fn with_children(mut self, children: impl IntoIterator<Item = Trace>) -> Self {
+    self.children.extend(children);
+    self
+  }
+
+  pub fn push(&mut self, child: Trace) {
+    self.children.push(child)
+  }
+
+  /// Pretty-printed JSON of the whole tree.
+  pub fn to_json(&self) -> String {
+    serde_json::to_string_pretty(self).unwrap()
+  }
+
+  fn write_children(&self, f: &mut std::fmt::Formatter<'_>, prefix: &str) -> std::fmt::Result {
+    for (idx, child) in self.children.iter().enumerate() {
+      let last = idx + 1 == self.children.len();
+      write!(f, "{prefix}{}", if last { "└── " } else { "├── " })?;
+      child.write_node(f)?;
+      child.write_children(
+        f,
+        &format!("{prefix}{}", if last { "    " } else { "│   " }),
+      )?;
+    }
+    Ok(())
+  }
+
+  fn write_node(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match &self.value {
+      Some(value) => writeln!(f, "{}: {value}", self.label),
+      None => writeln!(f, "{}", self.label),
+    }
+  }
+}
+
+impl Display for Trace {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    self.write_node(f)?;
+    self.write_children(f, "")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn trace() -> Trace {
+    Trace::new("Day 0").with_children([
+      Trace::new("Part 1").with_children([Trace::leaf("a", 1), Trace::leaf("b", 2)]),
+      Trace::leaf("Part 2", "done"),
+    ])
+  }
+
+  #[test]
+  fn test_display() {
+    assert_eq!(
+      trace().to_string(),
+      "Day 0
+├── Part 1
+│   ├── a: 1
+│   └── b: 2
+└── Part 2: done
+"
+    );
+  }
+
+  #[test]
+  fn test_to_json() {
+    assert_eq!(
+      trace().to_json(),
+      r#"{
+  "label": "Day 0",
+  "children": [
+    {
+      "label": "Part 1",
+      "children": [
+        {
+          "label": "a",
+          "value": "1"
+        },
+        {
+          "label": "b",
+          "value": "2"
+        }
+      ]
+    },
+    {
+      "label": "Part 2",
+      "value": "done"
+    }
+  ]
+}"#
+    );
+  }
+}
@@ -0,0 +1,227 @@
+//! Unbounded two-dimensional grids holding only their occupied cells.
+
+use std::{collections::BTreeMap, fmt::Display};
+
+/// A cell position as `[x, y]`, `y` growing downwards.
+pub type Point = [i64; 2];
+
+/// A rectangle of cells, both corners included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+  pub min: Point,
+  pub max: Point,
+}
+
+impl Bounds {
+  pub fn new(min: Point, max: Point) -> Self {
+    Self { min, max }
+  }
+
+  pub fn contains(&self, [x, y]: Point) -> bool {
+    (self.min[0]..=self.max[0]).contains(&x) && (self.min[1]..=self.max[1]).contains(&y)
+  }
+
+  /// The smallest rectangle holding both this one and `point`.
+  pub fn extend(&self, [x, y]: Point) -> Self {
+    Self {
+      min: [self.min[0].min(x), self.min[1].min(y)],
+      max: [self.max[0].max(x), self.max[1].max(y)],
+    }
+  }
+
+  pub fn width(&self) -> usize {
+    (self.max[0] - self.min[0] + 1).max(0) as usize
+  }
+
+  pub fn height(&self) -> usize {
+    (self.max[1] - self.min[1] + 1).max(0) as usize
+  }
+
+  pub fn area(&self) -> usize {
+    self.width() * self.height()
+  }
+}
+
+/// Cells at signed positions, kept in row order along with their bounding box.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+  /// Keyed by `(y, x)` so that iterating goes row by row.
+  cells: BTreeMap<(i64, i64), T>,
+  bounds: Option<Bounds>,
+}
+
+impl<T> Default for SparseGrid<T> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<T> SparseGrid<T> {
+  pub fn new() -> Self {
+    Self {
+      cells: BTreeMap::new(),
+      bounds: None,
+    }
+  }
+
+  pub fn len(&self) -> usize {
+    self.cells.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.cells.is_empty()
+  }
+
+  pub fn get(&self, [x, y]: Point) -> Option<&T> {
+    self.cells.get(&(y, x))
+  }
+
+  pub fn contains(&self, point: Point) -> bool {
+    self.get(point).is_some()
+  }
+
+  /// Places `cell` at `point`, returning the cell it replaces.
+  pub fn insert(&mut self, point @ [x, y]: Point, cell: T) -> Option<T> {
+    self.bounds = Some(match self.bounds {
+      Some(bounds) => bounds.extend(point),
+      None => Bounds::new(point, point),
+    });
+    self.cells.insert((y, x), cell)
+  }
+
+  pub fn remove(&mut self, [x, y]: Point) -> Option<T> {
+    let cell = self.cells.remove(&(y, x))?;
+    // Only a cell on the edge can shrink the box.
+    if let Some(Bounds { min, max }) = self.bounds {
+      if x == min[0] || x == max[0] || y == min[1] || y == max[1] {
+        self.bounds = self.recompute_bounds();
+      }
+    }
+    Some(cell)
+  }
+
+  fn recompute_bounds(&self) -> Option<Bounds> {
+    let (&(min_y, _), _) = self.cells.first_key_value()?;
+    let (&(max_y, _), _) = self.cells.last_key_value()?;
+    let min_x = self.cells.keys().map(|&(_, x)| x).min()?;
+    let max_x = self.cells.keys().map(|&(_, x)| x).max()?;
+    Some(Bounds::new([min_x, min_y], [max_x, max_y]))
+  }
+
+  /// The smallest rectangle holding every cell, if there is any.
+  pub fn bounds(&self) -> Option<Bounds> {
+    self.bounds
+  }
+
+  /// Every cell with its position, row by row from the top and left to right.
+  pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+    self.cells.iter().map(|(&(y, x), cell)| ([x, y], cell))
+  }
+
+  /// Every occupied position, in the order of [`SparseGrid::iter`].
+  pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+    self.cells.keys().map(|&(y, x)| [x, y])
+  }
+
+  /// Number of unoccupied positions within the bounding box.
+  pub fn empty_cells(&self) -> usize {
+    self.bounds.map_or(0, |bounds| bounds.area() - self.len())
+  }
+
+  /// Renders the cells within `area`, drawing `empty` where there is none.
+  pub fn viewport(&self, area: Bounds, empty: T) -> Viewport<'_, T> {
+    Viewport {
+      grid: self,
+      area: Some(area),
+      empty,
+    }
+  }
+
+  /// Renders the whole bounding box, drawing `empty` where there is no cell.
+  pub fn view(&self, empty: T) -> Viewport<'_, T> {
+    Viewport {
+      grid: self,
+      area: self.bounds,
+      empty,
+    }
+  }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+  fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+    let mut grid = Self::new();
+    grid.extend(iter);
+    grid
+  }
+}
+
+impl<T> Extend<(Point, T)> for SparseGrid<T> {
+  fn extend<I: IntoIterator<Item = (Point, T)>>(&mut self, iter: I) {
+    for (point, cell) in iter {
+      self.insert(point, cell);
+    }
+  }
+}
+
+/// A rectangle of a [`SparseGrid`], displayed one row per line.
+pub struct Viewport<'a, T> {
+  grid: &'a SparseGrid<T>,
+  area: Option<Bounds>,
+  empty: T,
+}
+
+impl<T: Display> Display for Viewport<'_, T> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let Some(area) = self.area else {
+      return Ok(());
+    };
+    for y in area.min[1]..=area.max[1] {
+      for x in area.min[0]..=area.max[0] {
+        write!(f, "{}", self.grid.get([x, y]).unwrap_or(&self.empty))?;
+      }
+      writeln!(f)?;
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_bounds() {
+    let mut grid = [[2, -1], [-3, 4], [0, 0]]
+      .into_iter()
+      .map(|point| (point, '#'))
+      .collect::<SparseGrid<_>>();
+
+    assert_eq!(grid.bounds(), Some(Bounds::new([-3, -1], [2, 4])));
+    assert_eq!(grid.empty_cells(), 6 * 6 - 3);
+    assert_eq!(
+      grid.points().collect::<Vec<_>>(),
+      [[2, -1], [0, 0], [-3, 4]]
+    );
+
+    grid.remove([-3, 4]);
+    assert_eq!(grid.bounds(), Some(Bounds::new([0, -1], [2, 0])));
+    grid.remove([0, 0]);
+    grid.remove([2, -1]);
+    assert_eq!(grid.bounds(), None);
+    assert_eq!(grid.empty_cells(), 0);
+  }
+
+  #[test]
+  fn test_viewport() {
+    let grid = [[0, 0], [2, 1]]
+      .into_iter()
+      .map(|point| (point, '#'))
+      .collect::<SparseGrid<_>>();
+
+    assert_eq!(grid.view('.').to_string(), "#..\n..#\n");
+    assert_eq!(
+      grid.viewport(Bounds::new([-1, 1], [3, 2]), ' ').to_string(),
+      "   # \n     \n"
+    );
+  }
+}
@@ -0,0 +1,280 @@
+//! A log of every run of the solvers, to spot days that got slower or changed their answer.
+
+use std::{
+  fmt::Display,
+  fs::{self, OpenOptions},
+  io::{self, Write},
+  path::Path,
+  process::Command,
+  time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::answer::Answer;
+
+/// Where the runner keeps its history: in the crate root whichever directory it runs from, and
+/// outside `target` so that `cargo clean` keeps it.
+pub const PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/.aoc/history.jsonl");
+
+/// Runs slower than the previous one by more than this factor are flagged.
+const SLOWDOWN: f64 = 1.2;
+
+/// The build profile of a run, as timings only compare within the same one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Profile {
+  Debug,
+  Release,
+}
+
+impl Profile {
+  /// The profile this crate was built with.
+  pub fn current() -> Self {
+    if cfg!(debug_assertions) {
+      Profile::Debug
+    } else {
+      Profile::Release
+    }
+  }
+}
+
+impl Display for Profile {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Profile::Debug => write!(f, "debug"),
+      Profile::Release => write!(f, "release"),
+    }
+  }
+}
+
+/// A single run of a part.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+  /// Short hash of the checked out commit, if the crate is in a git repository.
+  pub commit: Option<String>,
+  /// Seconds since the Unix epoch.
+  pub timestamp: u64,
+  pub day: u8,
+  pub part: u8,
+  /// [`answer_hash`] of the answer, so that answers are not stored in the clear.
+  pub answer_hash: u64,
+  /// Whether the answer is proven right, rather than the best one found within a time limit.
+  pub optimal: bool,
+  pub profile: Profile,
+  pub duration: Duration,
+}
+
+impl Record {
+  /// A record of a run finishing now, at the current commit and with the current profile.
+  pub fn new(day: u8, part: u8, answer: &Answer, optimal: bool, duration: Duration) -> Self {
+    Self {
+      commit: current_commit(),
+      timestamp: SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs()),
+      day,
+      part,
+      answer_hash: answer_hash(answer),
+      optimal,
+      profile: Profile::current(),
+      duration,
+    }
+  }
+}
+
+/// FNV-1a hash of the normalized answer, stable across Rust versions unlike `DefaultHasher`.
+pub fn answer_hash(answer: &Answer) -> u64 {
+  answer
+    .normalized()
+    .to_string()
+    .bytes()
+    .fold(0xcbf29ce484222325, |hash, byte| {
+      (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+fn current_commit() -> Option<String> {
+  let output = Command::new("git")
+    .args(["rev-parse", "--short", "HEAD"])
+    .output()
+    .ok()?;
+  output
+    .status
+    .success()
+    .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Appends a record to the history file, one JSON object per line.
+pub fn append(path: impl AsRef<Path>, record: &Record) -> io::Result<()> {
+  let path = path.as_ref();
+  if let Some(parent) = path.parent() {
+    fs::create_dir_all(parent)?;
+  }
+  let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+  writeln!(file, "{}", serde_json::to_string(record)?)
+}
+
+/// Every record of the history file, oldest first. A missing file is an empty history.
+pub fn load(path: impl AsRef<Path>) -> io::Result<Vec<Record>> {
+  let contents = match fs::read_to_string(path) {
+    Ok(contents) => contents,
+    Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+    Err(err) => return Err(err),
+  };
+  contents
+    .lines()
+    .filter(|line| !line.trim().is_empty())
+    .map(|line| serde_json::from_str(line).map_err(io::Error::from))
+    .collect()
+}
+
+/// The runs of a single part built with the same profile, with what changed from one run to
+/// the next.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trend<'a> {
+  pub part: u8,
+  pub profile: Profile,
+  pub runs: Vec<&'a Record>,
+}
+
+impl Trend<'_> {
+  /// How much slower each run was than the one before, `1.0` meaning just as fast.
+  pub fn slowdowns(&self) -> impl Iterator<Item = f64> + '_ {
+    self
+      .runs
+      .windows(2)
+      .map(|pair| pair[1].duration.as_secs_f64() / pair[0].duration.as_secs_f64())
+  }
+
+  /// Indices of the optimal runs whose answer differs from the optimal run before. Answers
+  /// cut short by a time limit are expected to differ, so they are left out.
+  pub fn answer_changes(&self) -> impl Iterator<Item = usize> + '_ {
+    let mut previous = None;
+    self
+      .runs
+      .iter()
+      .enumerate()
+      .filter(|(_, run)| run.optimal)
+      .filter_map(move |(idx, run)| {
+        let changed = previous.is_some_and(|hash| hash != run.answer_hash);
+        previous = Some(run.answer_hash);
+        changed.then_some(idx)
+      })
+  }
+}
+
+impl Display for Trend<'_> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    writeln!(f, "Part {} ({})", self.part, self.profile)?;
+    let slowdowns = std::iter::once(None).chain(self.slowdowns().map(Some));
+    let changes = self.answer_changes().collect::<Vec<_>>();
+
+    for (idx, (run, slowdown)) in self.runs.iter().zip(slowdowns).enumerate() {
+      write!(
+        f,
+        "  {:<9} {:>12?}",
+        run.commit.as_deref().unwrap_or("-"),
+        run.duration
+      )?;
+      if let Some(slowdown) = slowdown {
+        write!(f, " {:+6.1}%", (slowdown - 1.0) * 100.0)?;
+        if slowdown > SLOWDOWN {
+          write!(f, " slower")?;
+        }
+      }
+      if !run.optimal {
+        write!(f, " not optimal")?;
+      }
+      if changes.contains(&idx) {
+        write!(f, " answer changed")?;
+      }
+      writeln!(f)?;
+    }
+    Ok(())
+  }
+}
+
+/// The trend of every part of `day` that has been run, in part order then debug before
+/// release.
+pub fn trends(records: &[Record], day: u8) -> Vec<Trend<'_>> {
+  (1..=2)
+    .flat_map(|part| [Profile::Debug, Profile::Release].map(|profile| (part, profile)))
+    .map(|(part, profile)| Trend {
+      part,
+      profile,
+      runs: records
+        .iter()
+        .filter(|record| record.day == day && record.part == part && record.profile == profile)
+        .collect(),
+    })
+    .filter(|trend| !trend.runs.is_empty())
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn record(part: u8, answer: u32, millis: u64) -> Record {
+    Record {
+      commit: Some("abc1234".to_string()),
+      timestamp: 0,
+      day: 1,
+      part,
+      answer_hash: answer_hash(&answer.into()),
+      optimal: true,
+      profile: Profile::Debug,
+      duration: Duration::from_millis(millis),
+    }
+  }
+
+  #[test]
+  fn test_append_and_load() {
+    let path = std::env::temp_dir().join(format!("aoc-history-{}.jsonl", std::process::id()));
+    let records = [record(1, 7, 10), record(2, 19, 20)];
+    for record in &records {
+      append(&path, record).unwrap();
+    }
+    let loaded = load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded, records);
+    assert_eq!(load(&path).unwrap(), []);
+  }
+
+  #[test]
+  fn test_trends() {
+    let records = [
+      record(1, 7, 10),
+      record(2, 19, 20),
+      record(1, 7, 15),
+      record(1, 8, 15),
+    ];
+    let trends = trends(&records, 1);
+
+    assert_eq!(trends.len(), 2);
+    assert_eq!(trends[0].slowdowns().collect::<Vec<_>>(), [1.5, 1.0]);
+    assert_eq!(trends[0].answer_changes().collect::<Vec<_>>(), [2]);
+    assert!(trends[0].to_string().contains("+50.0% slower"));
+    assert_eq!(trends[1].answer_changes().count(), 0);
+
+    let cut_short = Record {
+      optimal: false,
+      ..record(1, 9, 15)
+    };
+    let release = Record {
+      profile: Profile::Release,
+      ..record(1, 8, 1)
+    };
+    let records = [record(1, 7, 10), cut_short, record(1, 7, 10), release];
+    let by_profile = super::trends(&records, 1);
+
+    assert_eq!(by_profile.len(), 2);
+    assert_eq!(by_profile[0].runs.len(), 3);
+    assert_eq!(by_profile[0].answer_changes().count(), 0);
+    assert!(by_profile[0].to_string().contains("not optimal"));
+    assert_eq!(by_profile[1].profile, Profile::Release);
+    assert!(by_profile[1].to_string().starts_with("Part 1 (release)"));
+  }
+}
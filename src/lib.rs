@@ -1 +1,49 @@
+// Lets the derives of `aoc_derive` name this crate as `aoc_2022` from inside it too.
+extern crate self as aoc_2022;
 
+pub mod answer;
+pub mod automaton;
+pub mod bench;
+pub mod cancel;
+pub mod checked;
+pub mod config;
+pub mod cycle;
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod explain;
+pub mod grid;
+pub mod history;
+pub mod math;
+pub mod matrix;
+pub mod parsers;
+pub mod registry;
+pub mod samples;
+pub mod scaling;
+pub mod search;
+pub mod status;
+pub mod tile;
+pub mod vault;
+pub mod voxel;
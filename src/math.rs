@@ -0,0 +1,208 @@
+//! Number theory that keeps coming back in the puzzles: common multiples of periods, modular
+//! arithmetic and overflow-checked folds.
+
+use std::{
+  fmt::Display,
+  ops::{Add, Mul, Neg, Sub},
+};
+
+use num::{traits::CheckedMul, CheckedAdd, Integer};
+
+/// Greatest common divisor of all `values`, 0 if there are none.
+pub fn gcd<T: Integer + Copy>(values: impl IntoIterator<Item = T>) -> T {
+  values
+    .into_iter()
+    .fold(T::zero(), |acc, value| acc.gcd(&value))
+}
+
+/// Least common multiple of all `values`, 1 if there are none.
+pub fn lcm<T: Integer + Copy>(values: impl IntoIterator<Item = T>) -> T {
+  values
+    .into_iter()
+    .fold(T::one(), |acc, value| acc.lcm(&value))
+}
+
+/// Like [`lcm`], but `None` if the result overflows.
+pub fn checked_lcm<T: Integer + CheckedMul + Copy>(
+  values: impl IntoIterator<Item = T>,
+) -> Option<T> {
+  values.into_iter().try_fold(T::one(), |acc, value| {
+    if value.is_zero() {
+      return Some(T::zero());
+    }
+    (acc / acc.gcd(&value)).checked_mul(&value)
+  })
+}
+
+/// Sum of all `values`, `None` if it overflows.
+pub fn checked_sum<T: CheckedAdd + num::Zero>(values: impl IntoIterator<Item = T>) -> Option<T> {
+  values
+    .into_iter()
+    .try_fold(T::zero(), |acc, value| acc.checked_add(&value))
+}
+
+/// Product of all `values`, `None` if it overflows.
+pub fn checked_product<T: CheckedMul + num::One>(values: impl IntoIterator<Item = T>) -> Option<T> {
+  values
+    .into_iter()
+    .try_fold(T::one(), |acc, value| acc.checked_mul(&value))
+}
+
+/// Solves `x ≡ residue (mod modulus)` for every `(residue, modulus)` pair with the Chinese
+/// remainder theorem. The moduli need not be coprime.
+///
+/// Returns the smallest non-negative solution and the lcm of the moduli, every solution
+/// being congruent to it modulo that lcm. `None` if the congruences contradict each other or
+/// the lcm overflows.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+  congruences
+    .into_iter()
+    .try_fold((0, 1), |(x, modulus): (i64, i64), (residue, other)| {
+      let residue = residue.rem_euclid(other);
+      let gcd = modulus.extended_gcd(&other);
+      let difference = residue - x;
+      if difference % gcd.gcd != 0 {
+        return None;
+      }
+      let lcm = (modulus / gcd.gcd).checked_mul(other)?;
+      // x + modulus * k solves both once k ≡ difference / gcd * inverse (mod other / gcd).
+      let step = (other / gcd.gcd) as i128;
+      let k = (difference / gcd.gcd) as i128 * gcd.x as i128 % step;
+      let x = (x as i128 + modulus as i128 * k).rem_euclid(lcm as i128);
+      Some((x as i64, lcm))
+    })
+}
+
+/// An integer modulo a modulus chosen at runtime, such as the length of a list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ModInt {
+  value: u64,
+  modulus: u64,
+}
+
+impl ModInt {
+  /// `value` reduced modulo `modulus`, negative values wrapping around.
+  pub fn new(value: i64, modulus: u64) -> Self {
+    assert!(modulus > 0, "the modulus must be positive");
+    Self {
+      value: (value as i128).rem_euclid(modulus as i128) as u64,
+      modulus,
+    }
+  }
+
+  /// The representative in `0..modulus`.
+  pub fn value(self) -> u64 {
+    self.value
+  }
+
+  pub fn modulus(self) -> u64 {
+    self.modulus
+  }
+
+  pub fn pow(self, mut exponent: u64) -> Self {
+    let mut base = self;
+    let mut result = Self::new(1, self.modulus);
+    while exponent > 0 {
+      if exponent & 1 == 1 {
+        result = result * base;
+      }
+      base = base * base;
+      exponent >>= 1;
+    }
+    result
+  }
+
+  /// The multiplicative inverse, if the value is coprime with the modulus.
+  pub fn inverse(self) -> Option<Self> {
+    let gcd = (self.value as i128).extended_gcd(&(self.modulus as i128));
+    (gcd.gcd == 1).then(|| Self {
+      value: gcd.x.rem_euclid(self.modulus as i128) as u64,
+      modulus: self.modulus,
+    })
+  }
+
+  fn with(self, other: Self, value: u128) -> Self {
+    assert_eq!(self.modulus, other.modulus, "mixing different moduli");
+    Self {
+      value: (value % self.modulus as u128) as u64,
+      modulus: self.modulus,
+    }
+  }
+}
+
+impl Add for ModInt {
+  type Output = Self;
+
+  fn add(self, other: Self) -> Self {
+    self.with(other, self.value as u128 + other.value as u128)
+  }
+}
+
+impl Sub for ModInt {
+  type Output = Self;
+
+  fn sub(self, other: Self) -> Self {
+    self.with(
+      other,
+      self.value as u128 + self.modulus as u128 - other.value as u128,
+    )
+  }
+}
+
+impl Mul for ModInt {
+  type Output = Self;
+
+  fn mul(self, other: Self) -> Self {
+    self.with(other, self.value as u128 * other.value as u128)
+  }
+}
+
+impl Neg for ModInt {
+  type Output = Self;
+
+  fn neg(self) -> Self {
+    Self::new(0, self.modulus) - self
+  }
+}
+
+impl Display for ModInt {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{} (mod {})", self.value, self.modulus)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_multiples() {
+    assert_eq!(gcd([12, 18, 27]), 3);
+    assert_eq!(lcm([23u64, 19, 13, 17]), 96577);
+    assert_eq!(lcm([4, 6]), 12);
+    assert_eq!(checked_lcm([u64::MAX, 2]), None);
+    assert_eq!(checked_product([1u8, 2, 3, 4, 5]), Some(120));
+    assert_eq!(checked_product([16u8, 16]), None);
+    assert_eq!(checked_sum([i32::MAX, 1]), None);
+    assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+    assert_eq!(crt([(1, 4), (3, 6)]), Some((9, 12)));
+    assert_eq!(crt([(1, 4), (2, 6)]), None);
+  }
+
+  #[test]
+  fn test_mod_int() {
+    let modulus = 4999;
+    let a = ModInt::new(-3, modulus);
+
+    assert_eq!(a.value(), 4996);
+    assert_eq!((a + ModInt::new(5, modulus)).value(), 2);
+    assert_eq!(
+      (ModInt::new(2, modulus) - ModInt::new(5, modulus)).value(),
+      4996
+    );
+    assert_eq!((a * a.inverse().unwrap()).value(), 1);
+    assert_eq!(ModInt::new(3, 7).pow(6).value(), 1);
+    assert_eq!(ModInt::new(2, 4).inverse(), None);
+    assert_eq!((-ModInt::new(1, 7)).to_string(), "6 (mod 7)");
+  }
+}
@@ -0,0 +1,232 @@
+//! Checking every day against the inputs and answers of several people, laid out as
+//! `<dir>/<user>/day_N`.
+//!
+//! The expected answers of an input sit next to it in `day_N.answers`, one line per part. An
+//! empty or missing line leaves that part unchecked.
+
+use std::{
+  fmt::Display,
+  fs, io,
+  path::{Path, PathBuf},
+};
+
+use crate::{answer::Answer, cancel::Best};
+
+/// Where the expected answers of the input at `input` are stored.
+pub fn answers_path(input: impl AsRef<Path>) -> PathBuf {
+  let mut answers = input.as_ref().as_os_str().to_owned();
+  answers.push(".answers");
+  PathBuf::from(answers)
+}
+
+/// The expected answers of both parts, none if the file does not exist.
+pub fn read_answers(path: impl AsRef<Path>) -> io::Result<[Option<Answer>; 2]> {
+  let contents = match fs::read_to_string(path) {
+    Ok(contents) => contents,
+    Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok([None, None]),
+    Err(err) => return Err(err),
+  };
+  let mut lines = contents.lines().map(|line| {
+    let line = line.trim();
+    (!line.is_empty()).then(|| line.parse().unwrap())
+  });
+  Ok([lines.next().flatten(), lines.next().flatten()])
+}
+
+/// How a part did on someone's input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+  Pass,
+  Fail {
+    expected: Answer,
+    actual: Answer,
+  },
+  /// Solved, but there is no answer to check it against.
+  Unchecked(Answer),
+  /// The search ran out of time, with the best answer it had found.
+  Timeout(Answer),
+  /// The input could not be read or the solver panicked.
+  Error(String),
+  NoInput,
+}
+
+impl Outcome {
+  /// Compares what a solver returned with the expected answer, if there is one.
+  pub fn check(expected: Option<&Answer>, actual: Result<Answer, String>) -> Self {
+    match (expected, actual) {
+      (_, Err(err)) => Outcome::Error(err),
+      (None, Ok(actual)) => Outcome::Unchecked(actual),
+      (Some(expected), Ok(actual)) if *expected == actual => Outcome::Pass,
+      (Some(expected), Ok(actual)) => Outcome::Fail {
+        expected: expected.clone(),
+        actual,
+      },
+    }
+  }
+
+  /// [`check`](Self::check) for a search stopped by a time limit, which timed out if its
+  /// answer is not proven optimal.
+  pub fn check_best(expected: Option<&Answer>, actual: Result<Best<Answer>, String>) -> Self {
+    match actual {
+      Ok(best) if !best.optimal => Outcome::Timeout(best.value),
+      actual => Self::check(expected, actual.map(|best| best.value)),
+    }
+  }
+
+  pub fn is_failure(&self) -> bool {
+    matches!(
+      self,
+      Outcome::Fail { .. } | Outcome::Timeout(_) | Outcome::Error(_)
+    )
+  }
+
+  fn symbol(&self) -> char {
+    match self {
+      Outcome::Pass => '✓',
+      Outcome::Fail { .. } => '✗',
+      Outcome::Unchecked(_) => '?',
+      Outcome::Timeout(_) => '…',
+      Outcome::Error(_) => '!',
+      Outcome::NoInput => '-',
+    }
+  }
+}
+
+impl Display for Outcome {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Outcome::Pass => write!(f, "pass"),
+      Outcome::Fail { expected, actual } => write!(f, "expected {expected}, got {actual}"),
+      Outcome::Unchecked(answer) => write!(f, "{answer}, unchecked"),
+      Outcome::Timeout(best) => write!(f, "timed out, best so far {best}"),
+      Outcome::Error(err) => write!(f, "{err}"),
+      Outcome::NoInput => write!(f, "no input"),
+    }
+  }
+}
+
+/// The outcome of every part of every day on every user's input.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Matrix {
+  pub users: Vec<String>,
+  /// Each day with the outcomes of its parts for every user, in the order of `users`.
+  pub days: Vec<(u8, Vec<Vec<Outcome>>)>,
+}
+
+impl Matrix {
+  /// Every outcome that is a failure, with its user, day and part.
+  pub fn failures(&self) -> impl Iterator<Item = (&str, u8, usize, &Outcome)> {
+    self.days.iter().flat_map(move |(day, users)| {
+      users
+        .iter()
+        .zip(&self.users)
+        .flat_map(move |(parts, user)| {
+          parts
+            .iter()
+            .enumerate()
+            .filter(|(_, outcome)| outcome.is_failure())
+            .map(move |(idx, outcome)| (user.as_str(), *day, idx + 1, outcome))
+        })
+    })
+  }
+}
+
+/// A table with a row per day and a column per user, one symbol per part: `✓` passed, `✗`
+/// failed, `?` unchecked, `…` timed out, `!` error and `-` no input.
+impl Display for Matrix {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let widths = self
+      .users
+      .iter()
+      .map(|user| user.chars().count().max(2))
+      .collect::<Vec<_>>();
+
+    write!(f, "day")?;
+    for (user, width) in self.users.iter().zip(&widths) {
+      write!(f, "  {user:<width$}")?;
+    }
+    writeln!(f)?;
+    for (day, users) in &self.days {
+      write!(f, "{day:>3}")?;
+      for (parts, width) in users.iter().zip(&widths) {
+        let symbols = parts.iter().map(Outcome::symbol).collect::<String>();
+        write!(f, "  {symbols:<width$}")?;
+      }
+      writeln!(f)?;
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_check() {
+    let expected = Answer::from(42);
+
+    assert_eq!(
+      Outcome::check(Some(&expected), Ok("42".into())),
+      Outcome::Pass
+    );
+    assert!(Outcome::check(Some(&expected), Ok(41.into())).is_failure());
+    assert_eq!(
+      Outcome::check(None, Ok(41.into())),
+      Outcome::Unchecked(41.into())
+    );
+    assert!(Outcome::check(None, Err("panicked".to_string())).is_failure());
+
+    let best = |value: u32, optimal| Ok(Best { value, optimal }.map(Answer::from));
+    assert_eq!(
+      Outcome::check_best(Some(&expected), best(42, true)),
+      Outcome::Pass
+    );
+    assert_eq!(
+      Outcome::check_best(Some(&expected), best(42, false)),
+      Outcome::Timeout(42.into())
+    );
+    assert!(Outcome::check_best(None, best(41, false)).is_failure());
+  }
+
+  #[test]
+  fn test_display() {
+    let matrix = Matrix {
+      users: vec!["alice".to_string(), "b".to_string()],
+      days: vec![
+        (
+          1,
+          vec![
+            vec![Outcome::Pass, Outcome::Unchecked(1.into())],
+            vec![Outcome::NoInput, Outcome::NoInput],
+          ],
+        ),
+        (
+          22,
+          vec![
+            vec![Outcome::Pass, Outcome::Error("panicked".to_string())],
+            vec![
+              Outcome::Pass,
+              Outcome::Fail {
+                expected: 1.into(),
+                actual: 2.into(),
+              },
+            ],
+          ],
+        ),
+      ],
+    };
+
+    assert_eq!(
+      matrix.to_string(),
+      "day  alice  b \n  1  ✓?     --\n 22  ✓!     ✓✗\n"
+    );
+    assert_eq!(
+      matrix
+        .failures()
+        .map(|(user, day, part, _)| (user, day, part))
+        .collect::<Vec<_>>(),
+      [("alice", 22, 2), ("b", 22, 2)]
+    );
+  }
+}
@@ -0,0 +1,169 @@
+//! nom combinators for the shapes puzzle inputs keep coming in.
+
+use std::{fmt::Display, str::FromStr};
+
+use nom::{
+  bytes::complete::tag,
+  character::complete::{digit1, line_ending, multispace0, none_of, one_of},
+  combinator::{all_consuming, cut, map_opt, map_res, opt, recognize},
+  error::{Error, ErrorKind},
+  multi::{many1, many_m_n, separated_list1},
+  sequence::{pair, preceded, terminated},
+  Finish, IResult, Parser,
+};
+
+/// An unsigned number such as `42`.
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+  map_res(digit1, str::parse)(input)
+}
+
+/// A number with an optional sign, such as `-7` or `+3`.
+pub fn signed<T: FromStr>(input: &str) -> IResult<&str, T> {
+  map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse)(input)
+}
+
+/// One or more `item`s separated by `separator`, e.g. `list(", ", unsigned)` for `1, 2, 3`.
+///
+/// A separator must be followed by another item, so a bad item is reported where it starts.
+pub fn list<'a, O>(
+  separator: &'static str,
+  item: impl Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+  separated_list1(tag(separator), cut(item))
+}
+
+/// One `item` per line.
+pub fn lines<'a, O>(
+  item: impl Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+  separated_list1(line_ending, item)
+}
+
+/// Blocks separated by a blank line.
+pub fn blocks<'a, O>(
+  block: impl Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+  separated_list1(many_m_n(2, 2, line_ending), block)
+}
+
+/// Two `item`s separated by `separator`, such as the `498,4` of day 14.
+pub fn coordinate<'a, T>(
+  separator: &'static str,
+  item: impl Parser<&'a str, T, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (T, T)> {
+  let mut item = item;
+  move |input| {
+    let (input, first) = item.parse(input)?;
+    let (input, _) = tag(separator)(input)?;
+    let (input, second) = item.parse(input)?;
+    Ok((input, (first, second)))
+  }
+}
+
+/// A `name=value` field, such as the `rate=13` of day 16 or the `x=-2` of day 15.
+pub fn field<'a, O>(
+  name: &'static str,
+  value: impl Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O> {
+  preceded(pair(tag(name), tag("=")), value)
+}
+
+/// Rows of characters, each converted into a tile.
+pub fn grid<T: TryFrom<char>>(input: &str) -> IResult<&str, Vec<Vec<T>>> {
+  lines(many1(map_opt(none_of("\r\n"), |c| T::try_from(c).ok())))(input)
+}
+
+/// Where and why parsing stopped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+  /// 1-based line of the offending input.
+  pub line: usize,
+  /// 1-based column, counted in characters.
+  pub column: usize,
+  pub kind: ErrorKind,
+  /// The rest of the offending line.
+  pub snippet: String,
+}
+
+impl ParseError {
+  fn new(input: &str, error: Error<&str>) -> Self {
+    let offset = input.len() - error.input.len();
+    let consumed = &input[..offset];
+    let line_start = consumed.rfind('\n').map_or(0, |idx| idx + 1);
+
+    Self {
+      line: consumed.matches('\n').count() + 1,
+      column: consumed[line_start..].chars().count() + 1,
+      kind: error.code,
+      snippet: error.input.lines().next().unwrap_or_default().to_string(),
+    }
+  }
+}
+
+impl Display for ParseError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}:{}: expected {} at {:?}",
+      self.line,
+      self.column,
+      self.kind.description(),
+      self.snippet
+    )
+  }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Runs `parser` over the whole input, allowing trailing whitespace.
+pub fn parse_all<'a, O>(
+  parser: impl Parser<&'a str, O, Error<&'a str>>,
+  input: &'a str,
+) -> Result<O, ParseError> {
+  all_consuming(terminated(parser, multispace0))(input)
+    .finish()
+    .map(|(_, output)| output)
+    .map_err(|error| ParseError::new(input, error))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_combinators() {
+    assert_eq!(
+      parse_all(list(", ", unsigned::<u8>), "1, 2, 3"),
+      Ok(vec![1, 2, 3])
+    );
+    assert_eq!(
+      parse_all(lines(coordinate(",", signed::<i32>)), "1,-2\n+3,4\n"),
+      Ok(vec![(1, -2), (3, 4)])
+    );
+    assert_eq!(
+      parse_all(blocks(lines(unsigned::<u32>)), "1\n2\n\n3\n"),
+      Ok(vec![vec![1, 2], vec![3]])
+    );
+    assert_eq!(
+      parse_all(
+        preceded(tag("Valve AA has flow "), field("rate", unsigned::<u16>)),
+        "Valve AA has flow rate=13"
+      ),
+      Ok(13)
+    );
+    assert_eq!(
+      parse_all(grid::<char>, "#.\n.#"),
+      Ok(vec![vec!['#', '.'], vec!['.', '#']])
+    );
+  }
+
+  #[test]
+  fn test_error_location() {
+    let error = parse_all(lines(list(",", unsigned::<u32>)), "1,2\n3,x,4\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 3));
+    assert_eq!(error.snippet, "x,4");
+
+    let error = parse_all(grid::<u8>, "ab\nc€").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 2));
+  }
+}
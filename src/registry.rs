@@ -0,0 +1,546 @@
+//! Every day's solvers behind a common signature, so a single runner can drive them.
+
+use std::{
+  fmt::Debug,
+  panic::{self, AssertUnwindSafe},
+  path::PathBuf,
+};
+
+use crate::{
+  answer::Answer,
+  cancel::{Best, Cancellation},
+  config::Config,
+  explain::Trace,
+  scaling::Generator,
+  search::BoundStats,
+  vault::{self, VaultError},
+  *,
+};
+
+/// Solves a part from the raw puzzle input, taking the day's parameters from the config.
+pub type Solver = fn(&str, &Config) -> Answer;
+
+/// Solves a part by branch and bound, settling for the best answer found so far once the
+/// cancellation fires, and recording how the search pruned.
+pub type CancellableSolver = fn(&str, &Cancellation, &mut BoundStats) -> Best<Answer>;
+
+/// Another way of solving a part, picked by name.
+pub struct Implementation {
+  pub name: &'static str,
+  pub part: u8,
+  pub solver: Solver,
+}
+
+/// What the usual implementation of each part is called.
+pub const DEFAULT_IMPLEMENTATION: &str = "default";
+
+/// A day's solvers and where its puzzle input lives.
+pub struct Day {
+  pub day: u8,
+  pub title: &'static str,
+  /// Path of the puzzle input, relative to the crate root, unless the config moves it.
+  pub input: &'static str,
+  pub part_1: Option<Solver>,
+  pub part_2: Option<Solver>,
+  /// Variants of the parts that can be stopped early and report how their search pruned,
+  /// for days running long searches.
+  pub part_1_within: Option<CancellableSolver>,
+  pub part_2_within: Option<CancellableSolver>,
+  /// Intermediate results of both parts, for days that support it.
+  pub explain: Option<fn(&str, &Config) -> Trace>,
+  /// The parsed input, pretty-printed, for days with a parser.
+  pub parse: Option<fn(&str) -> String>,
+  /// Inputs of any size, for days whose solvers' scaling is measured.
+  pub generator: Option<Generator>,
+  /// Other implementations of the parts, next to the default ones.
+  pub alternatives: &'static [Implementation],
+}
+
+impl Day {
+  pub fn input_path(&self, config: &Config) -> PathBuf {
+    config.input_path(self.day, self.input)
+  }
+
+  /// The puzzle input, decrypted if only its encrypted version is around.
+  pub fn read_input(&self, config: &Config) -> Result<String, VaultError> {
+    vault::read_input(config, self.day, self.input)
+  }
+
+  /// The implemented parts, numbered from 1, with their cancellable variants.
+  pub fn parts(&self) -> impl Iterator<Item = (u8, Solver, Option<CancellableSolver>)> {
+    [
+      (1, self.part_1, self.part_1_within),
+      (2, self.part_2, self.part_2_within),
+    ]
+    .into_iter()
+    .filter_map(|(number, part, within)| Some((number, part?, within)))
+  }
+
+  /// Every implementation of `part` by name, the default one first.
+  pub fn implementations(&self, part: u8) -> Vec<(&'static str, Solver)> {
+    let default = match part {
+      1 => self.part_1,
+      2 => self.part_2,
+      _ => None,
+    };
+    default
+      .map(|solver| (DEFAULT_IMPLEMENTATION, solver))
+      .into_iter()
+      .chain(
+        self
+          .alternatives
+          .iter()
+          .filter(|implementation| implementation.part == part)
+          .map(|implementation| (implementation.name, implementation.solver)),
+      )
+      .collect()
+  }
+
+  /// The implementation of `part` called `name`.
+  pub fn implementation(&self, part: u8, name: &str) -> Option<Solver> {
+    self
+      .implementations(part)
+      .into_iter()
+      .find(|(candidate, _)| *candidate == name)
+      .map(|(_, solver)| solver)
+  }
+}
+
+fn pretty(parsed: impl Debug) -> String {
+  format!("{parsed:#?}")
+}
+
+/// Runs `f`, turning a panic of a solver on malformed input into an error so that the
+/// caller can go on with other inputs.
+pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+  let hook = panic::take_hook();
+  panic::set_hook(Box::new(|_| {}));
+  let result = panic::catch_unwind(AssertUnwindSafe(f));
+  panic::set_hook(hook);
+
+  result.map_err(|payload| {
+    let message = payload
+      .downcast_ref::<&str>()
+      .copied()
+      .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+      .unwrap_or("unknown panic");
+    format!("the input could not be solved: {message}")
+  })
+}
+
+/// Looks up a day by its number.
+pub fn get(day: u8) -> Option<&'static Day> {
+  DAYS.iter().find(|entry| entry.day == day)
+}
+
+pub static DAYS: [Day; 25] = [
+  Day {
+    day: 1,
+    title: "Calorie Counting",
+    input: "assets/day_1",
+    part_1: Some(|input, _| day1::part_1(input).into()),
+    part_2: Some(|input, _| day1::part_2(input).into()),
+    part_1_within: None,
+    part_2_within: None,
+    explain: None,
+    parse: Some(|input| pretty(day1::parse(input).collect::<Vec<_>>())),
+    generator: None,
+    alternatives: &[],
+  },
+  Day {
+    day: 2,
+    title: "Rock Paper Scissors",
+    input: "assets/day_2",
+    part_1: Some(|input, _| day2::part_1(input).into()),
+    part_2: Some(|input, _| day2::part_2(input).into()),
+    part_1_within: None,
+    part_2_within: None,
+    explain: None,
+    parse: Some(|input| pretty(day2::parse_part_1(input).collect::<Vec<_>>())),
+    generator: None,
+    alternatives: &[],
+  },
+  Day {
+    day: 3,
+    title: "Rucksack Reorganization",
+    input: "assets/day_3",
+    part_1: Some(|input, _| day3::part_1(input).into()),
+    part_2: Some(|input, _| day3::part_2(input).into()),
+    part_1_within: None,
+    part_2_within: None,
+    explain: None,
+    parse: Some(|input| pretty(day3::parse_part_1(input).collect::<Vec<_>>())),
+    generator: None,
+    alternatives: &[],
+  },
+  Day {
+    day: 4,
+    title: "Camp Cleanup",
+    input: "assets/day_4",
+    part_1: Some(|input, _| day4::part_1(input).into()),
+    part_2: Some(|input, _| day4::part_2(input).into()),
+    part_1_within: None,
+    part_2_within: None,
+    explain: None,
+    parse: Some(|input| pretty(day4::parse(input).collect::<Vec<_>>())),
+    generator: None,
+    alternatives: &[],
+  },
+  Day {
+    day: 5,
+    title: "Supply Stacks",
+    input: "assets/day_5",
+    part_1: Some(|input, _| day5::part_1(input).into()),
+    part_2: Some(|input, _| day5::part_2(input).into()),
+    part_1_within: None,
+    part_2_within: None,
+    explain: None,
+    parse: Some(|input| pretty(day5::parse(input))),
+    generator: None,
+    alternatives: &[],
+  },
+  Day {
+    day: 6,
+    title: "Tuning Trouble",
+    input: "assets/day_6",
+    part_1: Some(|input, _| day6::part_1(input).into()),
+    part_2: Some(|input, _| day6::part_2(input).into()),
+    part_1_within: None,
+    part_2_within: None,
+    explain: None,
+    parse: None,
+    generator: None,
+    alternatives: &[],
+  },
+  Day {
+    day: 7,
+    title: "No Space Left On Device",
+    input: "assets/day_7",
+    part_1: Some(|input, _| day7::part_1(input).into()),
+    part_2: Some(|input, _| day7::part_2(input).into()),
+    part_1_within: None,
+    part_2_within: None,
+    explain: Some(|input, _| day7::explain(input)),
+    parse: Some(|input| {
+      pretty(
+        input
+          .lines()
+          .map(|line| day7::parse_line(line).unwrap().1)
+          .collect::<Vec<_>>(),
+      )
+    }),
+    generator: None,
+    alternatives: &[],
+  },
+  Day {
+    day: 8,
+    title: "Treetop Tree House",
+    input: "assets/day_8",
+    part_1: Some(|input, _| day8::part_1(input).into()),
+    part_2: Some(|input, _| day8::part_2(input).into()),
+    part_1_within: None,
+    part_2_within: None,
+    explain: None,
+    parse: Some(|input| pretty(day8::parse(input))),
+    generator: Some(Generator {
+      generate: day8::generate,
+      unit: "trees per side",
+      smallest: 32,
+    }),
+    alternatives: &[],
+  },
+  Day {
+    day: 9,
+    title: "Rope Bridge",
+    input: "assets/day_9",
+    part_1: Some(|input, _| day9::part_1(input).into()),
+    part_2: Some(|input, _| day9::part_2(input).into()),
+    part_1_within: None,
+    part_2_within: None,
+    explain: None,
+    parse: Some(|input| pretty(day9::parse(input))),
+    generator: None,
+    alternatives: &[],
+  },
+  Day {
+    day: 10,
+    title: "Cathode-Ray Tube",
+    input: "assets/day_10",
+    part_1: Some(|input, _| day10::part_1(input).into()),
+    part_2: Some(|input, _| day10::part_2(input).into()),
+    part_1_within: None,
+    part_2_within: None,
+    explain: None,
+    parse: Some(|input| pretty(day10::parse(input))),
+    generator: None,
+    alternatives: &[],
+  },
+  Day {
+    day: 11,
+    title: "Monkey in the Middle",
+    input: "assets/day_11",
+    part_1: Some(|input, _| day11::part_1(input).into()),
+    part_2: Some(|input, _| day11::part_2(input).into()),
+    part_1_within: None,
+    part_2_within: None,
+    explain: Some(|input, _| day11::explain(input)),
+    parse: Some(|input| pretty(day11::parse(input))),
+    generator: None,
+    alternatives: &[],
+  },
+  Day {
+    day: 12,
+    title: "Hill Climbing Algorithm",
+    input: "assets/day_12",
+    part_1: Some(|input, _| day12::part_1(input).into()),
+    part_2: Some(|input, _| day12::part_2(input).into()),
+    part_1_within: None,
+    part_2_within: None,
+    explain: None,
+    parse: Some(|input| pretty(day12::parse(input))),
+    generator: Some(Generator {
+      generate: day12::generate,
+      unit: "squares per side",
+      smallest: 32,
+    }),
+    alternatives: &[
+      Implementation {
+        name: "descent",
+        part: 2,
+        solver: |input, _| day12::part_2_descent(input).into(),
+      },
+      Implementation {
+        name: "astar-each",
+        part: 2,
+        solver: |input, _| day12::part_2_astar_each(input).into(),
+      },
+    ],
+  },
+  Day {
+    day: 13,
+    title: "Distress Signal",
+    input: "assets/day_13",
+    part_1: Some(|input, _| day13::part_1(input).into()),
+    part_2: Some(|input, _| day13::part_2(input).into()),
+    part_1_within: None,
+    part_2_within: None,
+    explain: Some(|input, _| day13::explain(input)),
+    parse: Some(|input| pretty(day13::parse(input))),
+    generator: None,
+    alternatives: &[],
+  },
+  Day {
+    day: 14,
+    title: "Regolith Reservoir",
+    input: "assets/day_14",
+    part_1: Some(|input, _| day14::part_1(input).into()),
+    part_2: Some(|input, _| day14::part_2(input).into()),
+    part_1_within: None,
+    part_2_within: None,
+    explain: None,
+    parse: Some(|input| pretty(day14::parse(input))),
+    generator: None,
+    alternatives: &[],
+  },
+  Day {
+    day: 15,
+    title: "Beacon Exclusion Zone",
+    input: "assets/day_15",
+    part_1: Some(|input, config| day15::part_1(input, config.days.day15.row).into()),
+    part_2: Some(|input, config| day15::part_2(input, config.days.day15.bound).into()),
+    part_1_within: None,
+    part_2_within: None,
+    explain: None,
+    parse: Some(|input| pretty(day15::parse(input))),
+    generator: None,
+    alternatives: &[],
+  },
+  Day {
+    day: 16,
+    title: "Proboscidea Volcanium",
+    input: "src/bin/day_16/input",
+    part_1: Some(|input, _| day16::part_1(&day16::parse(input)).into()),
+    part_2: Some(|input, _| day16::part_2(&day16::parse(input)).into()),
+    part_1_within: Some(|input, cancellation, stats| {
+      day16::part_1_within(&day16::parse(input), cancellation, stats).map(Answer::from)
+    }),
+    part_2_within: Some(|input, cancellation, stats| {
+      day16::part_2_within(&day16::parse(input), cancellation, stats).map(Answer::from)
+    }),
+    explain: None,
+    parse: Some(|input| pretty(day16::parse(input))),
+    generator: None,
+    alternatives: &[],
+  },
+  Day {
+    day: 17,
+    title: "Pyroclastic Flow",
+    input: "src/bin/day_17/input",
+    part_1: Some(|input, config| day17::part_1(input, config.days.day17.rocks_1).into()),
+    part_2: Some(|input, config| day17::part_2(input, config.days.day17.rocks_2).into()),
+    part_1_within: None,
+    part_2_within: None,
+    explain: None,
+    parse: Some(|input| pretty(day17::parse_movement(input))),
+    generator: None,
+    alternatives: &[],
+  },
+  Day {
+    day: 18,
+    title: "Boiling Boulders",
+    input: "src/bin/day_18/input",
+    part_1: Some(|input, _| day18::part_1(input).into()),
+    part_2: Some(|input, _| day18::part_2(input).into()),
+    part_1_within: None,
+    part_2_within: None,
+    explain: None,
+    parse: Some(|input| pretty(day18::parse(input).map(|cubes| voxel::Voxels::voxels(&cubes)))),
+    generator: None,
+    alternatives: &[],
+  },
+  Day {
+    day: 19,
+    title: "Not Enough Minerals",
+    input: "src/bin/day_19/input",
+    part_1: Some(|input, _| day19::part_1(input).into()),
+    part_2: Some(|input, _| day19::part_2(input).into()),
+    part_1_within: Some(|input, cancellation, stats| {
+      day19::part_1_within(input, cancellation, stats).map(Answer::from)
+    }),
+    part_2_within: Some(|input, cancellation, stats| {
+      day19::part_2_within(input, cancellation, stats).map(Answer::from)
+    }),
+    explain: Some(|input, _| day19::explain(input)),
+    parse: Some(|input| pretty(day19::parse(input))),
+    generator: None,
+    alternatives: &[],
+  },
+  Day {
+    day: 20,
+    title: "Grove Positioning System",
+    input: "src/bin/day_20/input",
+    part_1: Some(|input, _| day20::part_1(input).into()),
+    part_2: Some(|input, config| day20::part_2(input, config.days.day20.decryption_key).into()),
+    part_1_within: None,
+    part_2_within: None,
+    explain: Some(|input, config| day20::explain(input, config.days.day20.decryption_key)),
+    parse: Some(|input| pretty(day20::parse(input))),
+    generator: Some(Generator {
+      generate: day20::generate,
+      unit: "numbers",
+      smallest: 250,
+    }),
+    alternatives: &[],
+  },
+  Day {
+    day: 21,
+    title: "Monkey Math",
+    input: "src/bin/day_21/input",
+    part_1: Some(|input, _| day21::part_1(input).into()),
+    part_2: Some(|input, _| day21::part_2(input).into()),
+    part_1_within: None,
+    part_2_within: None,
+    explain: None,
+    parse: Some(|input| pretty(day21::parse(input))),
+    generator: None,
+    alternatives: &[Implementation {
+      name: "symbolic",
+      part: 2,
+      solver: |input, _| day21::part_2_symbolic(input).into(),
+    }],
+  },
+  Day {
+    day: 22,
+    title: "Monkey Map",
+    input: "src/bin/day_22/input",
+    part_1: Some(|input, _| day22::part_1(input).into()),
+    part_2: Some(|input, config| day22::part_2(input, config.days.day22.face_size).into()),
+    part_1_within: None,
+    part_2_within: None,
+    explain: None,
+    parse: Some(|input| pretty(day22::parse(input))),
+    generator: None,
+    alternatives: &[],
+  },
+  Day {
+    day: 23,
+    title: "Unstable Diffusion",
+    input: "src/bin/day_23/input",
+    part_1: Some(|input, _| day23::part_1(input).into()),
+    part_2: Some(|input, _| day23::part_2(input).into()),
+    part_1_within: None,
+    part_2_within: None,
+    explain: None,
+    parse: Some(|input| pretty(input.trim().parse::<day23::Grove>())),
+    generator: None,
+    alternatives: &[],
+  },
+  Day {
+    day: 24,
+    title: "Blizzard Basin",
+    input: "src/bin/day_24/input",
+    part_1: Some(|input, _| day24::part_1(input).into()),
+    part_2: Some(|input, _| day24::part_2(input).into()),
+    part_1_within: None,
+    part_2_within: None,
+    explain: None,
+    parse: Some(|input| pretty(input.parse::<day24::Valley>())),
+    generator: None,
+    alternatives: &[],
+  },
+  Day {
+    day: 25,
+    title: "Full of Hot Air",
+    input: "src/bin/day_25/input",
+    part_1: Some(|input, _| day25::part_1(input).into()),
+    part_2: None,
+    part_1_within: None,
+    part_2_within: None,
+    explain: None,
+    parse: Some(|input| pretty(input.lines().map(day25::todec).collect::<Vec<_>>())),
+    generator: None,
+    alternatives: &[],
+  },
+];
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_days_in_order() {
+    assert!(DAYS
+      .iter()
+      .enumerate()
+      .all(|(idx, day)| day.day as usize == idx + 1));
+  }
+
+  #[test]
+  fn test_implementations() {
+    let day = get(21).unwrap();
+    let names = day
+      .implementations(2)
+      .into_iter()
+      .map(|(name, _)| name)
+      .collect::<Vec<_>>();
+    assert_eq!(names, [DEFAULT_IMPLEMENTATION, "symbolic"]);
+    assert!(day.implementation(1, "symbolic").is_none());
+  }
+
+  #[test]
+  fn test_solver() {
+    let day = get(6).unwrap();
+    let answers = day
+      .parts()
+      .map(|(_, part, _)| part("mjqjpqmgbljsphdztnvjfqwrcgsmlb", &Config::default()))
+      .collect::<Vec<_>>();
+    assert_eq!(answers, [Answer::Int(7), Answer::Int(19)]);
+  }
+
+  #[test]
+  fn test_parse() {
+    let parse = get(25).unwrap().parse.unwrap();
+    assert_eq!(parse("1=\n2-"), "[\n    3,\n    9,\n]");
+    assert!(get(6).unwrap().parse.is_none());
+  }
+}
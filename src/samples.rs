@@ -0,0 +1,103 @@
+//! Pulling the sample inputs and answers out of a saved puzzle page.
+//!
+//! The pages are simple enough that a handful of string searches does the job, and the
+//! samples have to come out byte for byte, trailing spaces included.
+
+use std::path::{Path, PathBuf};
+
+/// The text of every `<pre><code>` block, in page order, with tags removed and entities
+/// decoded.
+pub fn code_blocks(html: &str) -> Vec<String> {
+  between(html, "<pre><code>", "</code></pre>")
+    .map(|block| decode(&strip_tags(block)))
+    .collect()
+}
+
+/// The emphasised inline code of the page, such as the `<code><em>24000</em></code>` the
+/// puzzle text gives as the answer for the sample.
+pub fn answer_candidates(html: &str) -> Vec<String> {
+  between(html, "<code><em>", "</em></code>")
+    .map(|candidate| decode(&strip_tags(candidate)))
+    .collect()
+}
+
+/// Where the sample of `day` goes, for days whose module is a directory under `src`. Days
+/// kept in a single `src/dayN.rs` have their sample inline, so there is no such file.
+pub fn sample_path(src: impl AsRef<Path>, day: u8) -> Option<PathBuf> {
+  let module = src.as_ref().join(format!("day{day}"));
+  module.is_dir().then(|| module.join("sample"))
+}
+
+/// Where the answer candidates of the sample at `sample` go, one per line, to pick the
+/// expected answers of the sample tests from.
+pub fn candidates_path(sample: impl AsRef<Path>) -> PathBuf {
+  let mut candidates = sample.as_ref().as_os_str().to_owned();
+  candidates.push(".candidates");
+  PathBuf::from(candidates)
+}
+
+fn between<'a>(html: &'a str, open: &'a str, close: &'a str) -> impl Iterator<Item = &'a str> {
+  html.split(open).skip(1).filter_map(move |rest| {
+    let (inner, _) = rest.split_once(close)?;
+    Some(inner)
+  })
+}
+
+fn strip_tags(html: &str) -> String {
+  let mut text = String::with_capacity(html.len());
+  let mut in_tag = false;
+  for c in html.chars() {
+    match c {
+      '<' => in_tag = true,
+      '>' if in_tag => in_tag = false,
+      _ if !in_tag => text.push(c),
+      _ => (),
+    }
+  }
+  text
+}
+
+fn decode(text: &str) -> String {
+  text
+    .replace("&lt;", "<")
+    .replace("&gt;", ">")
+    .replace("&quot;", "\"")
+    .replace("&#39;", "'")
+    .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const PAGE: &str = "<article><p>For example:</p>\n\
+    <pre><code>    [D]    \n[N] [C]    \n</code></pre>\n\
+    <p>Moves look like <code>move 1</code>:</p>\n\
+    <pre><code>a &lt;<em>b</em>&gt; &amp;&amp; c\n</code></pre>\n\
+    <p>The answer is <code><em>CMZ</em></code>, not <code>42</code>.</p></article>";
+
+  #[test]
+  fn test_code_blocks() {
+    assert_eq!(
+      code_blocks(PAGE),
+      ["    [D]    \n[N] [C]    \n", "a <b> && c\n"]
+    );
+  }
+
+  #[test]
+  fn test_answer_candidates() {
+    assert_eq!(answer_candidates(PAGE), ["CMZ"]);
+    assert_eq!(
+      candidates_path("src/day17/sample"),
+      Path::new("src/day17/sample.candidates")
+    );
+    assert_eq!(
+      sample_path(env!("CARGO_MANIFEST_DIR").to_string() + "/src", 17),
+      Some(Path::new(env!("CARGO_MANIFEST_DIR")).join("src/day17/sample"))
+    );
+    assert_eq!(
+      sample_path(env!("CARGO_MANIFEST_DIR").to_string() + "/src", 1),
+      None
+    );
+  }
+}
@@ -0,0 +1,174 @@
+//! How the solvers' running time grows with the size of the input, measured on generated
+//! inputs of growing sizes.
+
+use std::{fmt::Display, time::Duration};
+
+/// A small seeded random number generator (splitmix64), so that a size always makes the same
+/// input.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+  pub fn new(seed: u64) -> Self {
+    Self(seed)
+  }
+
+  pub fn next_u64(&mut self) -> u64 {
+    self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+    let mut z = self.0;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+  }
+
+  /// A number in `0..bound`.
+  pub fn below(&mut self, bound: u64) -> u64 {
+    self.next_u64() % bound
+  }
+}
+
+/// Makes inputs of any size for a day.
+#[derive(Debug, Clone, Copy)]
+pub struct Generator {
+  pub generate: fn(usize, &mut Rng) -> String,
+  /// What the size counts, such as the trees along a side of the forest.
+  pub unit: &'static str,
+  /// The size of the first input, doubled at every step.
+  pub smallest: usize,
+}
+
+impl Generator {
+  /// The sizes of `steps` inputs, doubling from the smallest one.
+  pub fn sizes(&self, steps: u32) -> Vec<usize> {
+    (0..steps).map(|step| self.smallest << step).collect()
+  }
+}
+
+/// The exponent `k` of the power law `time = c * size^k` best fitting the runs, by least
+/// squares on their logarithms. `None` with fewer than two distinct sizes.
+pub fn exponent(runs: &[(usize, Duration)]) -> Option<f64> {
+  let points = runs
+    .iter()
+    .map(|&(size, duration)| {
+      (
+        (size as f64).ln(),
+        duration.as_secs_f64().max(f64::MIN_POSITIVE).ln(),
+      )
+    })
+    .collect::<Vec<_>>();
+  let n = points.len() as f64;
+  let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+  let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+  let variance = points
+    .iter()
+    .map(|(x, _)| (x - mean_x).powi(2))
+    .sum::<f64>();
+  let covariance = points
+    .iter()
+    .map(|(x, y)| (x - mean_x) * (y - mean_y))
+    .sum::<f64>();
+  (variance > 0.0).then(|| covariance / variance)
+}
+
+/// The timings of one solver across the sizes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Series {
+  pub label: String,
+  pub runs: Vec<(usize, Duration)>,
+}
+
+impl Display for Series {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    writeln!(f, "{}", self.label)?;
+    let mut previous: Option<Duration> = None;
+    for &(size, duration) in &self.runs {
+      write!(f, "  {size:>8} {:>12}", format!("{duration:.1?}"))?;
+      if let Some(previous) = previous {
+        write!(
+          f,
+          " {:>6.2}x",
+          duration.as_secs_f64() / previous.as_secs_f64()
+        )?;
+      }
+      writeln!(f)?;
+      previous = Some(duration);
+    }
+    match exponent(&self.runs) {
+      Some(exponent) => writeln!(f, "  time grows as size^{exponent:.2}"),
+      None => writeln!(f, "  too few sizes to fit an exponent"),
+    }
+  }
+}
+
+/// A whitespace-separated table for plotting tools such as gnuplot: a size per line, then the
+/// seconds of each series, with a comment naming the columns.
+pub fn plot_data(unit: &str, series: &[Series]) -> String {
+  let mut data = format!("# {unit}");
+  for series in series {
+    data.push_str(&format!("\t{}", series.label));
+  }
+  data.push('\n');
+  let sizes = series
+    .first()
+    .map(|series| {
+      series
+        .runs
+        .iter()
+        .map(|&(size, _)| size)
+        .collect::<Vec<_>>()
+    })
+    .unwrap_or_default();
+  for (idx, size) in sizes.into_iter().enumerate() {
+    data.push_str(&size.to_string());
+    for series in series {
+      match series.runs.get(idx) {
+        Some((_, duration)) => data.push_str(&format!("\t{:.9}", duration.as_secs_f64())),
+        None => data.push_str("\t?"),
+      }
+    }
+    data.push('\n');
+  }
+  data
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_exponent() {
+    let quadratic = [100, 200, 400, 800]
+      .map(|size: usize| (size, Duration::from_nanos((size * size) as u64 * 3)));
+    let linear = [
+      (10, Duration::from_millis(1)),
+      (1000, Duration::from_millis(100)),
+    ];
+
+    assert!((exponent(&quadratic).unwrap() - 2.0).abs() < 1e-6);
+    assert!((exponent(&linear).unwrap() - 1.0).abs() < 1e-6);
+    assert_eq!(exponent(&[(10, Duration::from_millis(1))]), None);
+  }
+
+  #[test]
+  fn test_plot_data() {
+    let series = [
+      Series {
+        label: "part_1".to_string(),
+        runs: vec![
+          (10, Duration::from_millis(1)),
+          (20, Duration::from_millis(4)),
+        ],
+      },
+      Series {
+        label: "part_2".to_string(),
+        runs: vec![(10, Duration::from_micros(5))],
+      },
+    ];
+
+    assert_eq!(
+      plot_data("numbers", &series),
+      "# numbers\tpart_1\tpart_2\n10\t0.001000000\t0.000005000\n20\t0.004000000\t?\n"
+    );
+    assert_eq!(Rng::new(7).next_u64(), Rng::new(7).next_u64());
+  }
+}
@@ -0,0 +1,324 @@
+//! Graph searches over anything that can list the successors of a state.
+
+use std::{
+  cmp::Reverse,
+  collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+  fmt::Display,
+  hash::Hash,
+  time::{Duration, Instant},
+};
+
+/// A graph explored lazily from one or more start states.
+pub trait SearchSpace {
+  type State: Clone + Eq + Hash;
+
+  /// States reachable in a single step.
+  fn successors(&self, state: &Self::State) -> Vec<Self::State>;
+
+  /// Cost of the step from `from` to its successor `to`.
+  fn cost(&self, _from: &Self::State, _to: &Self::State) -> u64 {
+    1
+  }
+
+  /// Lower bound of the remaining cost to a goal, used by [`astar`]. Must not overestimate.
+  fn heuristic(&self, _state: &Self::State) -> u64 {
+    0
+  }
+
+  fn is_goal(&self, _state: &Self::State) -> bool {
+    false
+  }
+}
+
+/// How much work a search did.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+  /// States whose successors were listed.
+  pub expanded: usize,
+  /// Successors produced, including ones that had been seen before.
+  pub generated: usize,
+  /// Largest number of states waiting to be expanded at once.
+  pub max_frontier: usize,
+}
+
+/// How well a depth-first branch-and-bound search pruned, reported to by the search itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoundStats {
+  /// Nodes whose branches were listed.
+  pub expanded: usize,
+  /// Branches dropped because their bound could not beat the best answer.
+  pub pruned: usize,
+  /// Deepest node expanded, the root being at depth 0.
+  pub max_depth: usize,
+  /// Times the best answer improved.
+  pub improvements: usize,
+  /// Time from the start of the search to its first complete answer.
+  pub first_solution: Option<Duration>,
+  started: Instant,
+}
+
+impl BoundStats {
+  /// Starts the clock for [`BoundStats::first_solution`].
+  pub fn start() -> Self {
+    Self {
+      expanded: 0,
+      pruned: 0,
+      max_depth: 0,
+      improvements: 0,
+      first_solution: None,
+      started: Instant::now(),
+    }
+  }
+
+  pub fn expand(&mut self, depth: usize) {
+    self.expanded += 1;
+    self.max_depth = self.max_depth.max(depth);
+  }
+
+  pub fn prune(&mut self) {
+    self.pruned += 1;
+  }
+
+  /// Records a complete answer, which `improved` on the best one so far or not.
+  pub fn solution(&mut self, improved: bool) {
+    if self.first_solution.is_none() {
+      self.first_solution = Some(self.started.elapsed());
+    }
+    if improved {
+      self.improvements += 1;
+    }
+  }
+}
+
+impl Display for BoundStats {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{} expanded, {} pruned by bound, depth {}, {} improvements",
+      self.expanded, self.pruned, self.max_depth, self.improvements
+    )?;
+    match self.first_solution {
+      Some(elapsed) => write!(f, ", first solution after {elapsed:?}"),
+      None => write!(f, ", no solution"),
+    }
+  }
+}
+
+/// The states from a start to a goal, both included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<T> {
+  pub states: Vec<T>,
+  pub cost: u64,
+}
+
+/// The path found, if any, and the work it took to find it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome<T> {
+  pub path: Option<Path<T>>,
+  pub stats: Stats,
+}
+
+struct Node<T> {
+  state: T,
+  parent: Option<usize>,
+  cost: u64,
+}
+
+fn reconstruct<T: Clone>(nodes: &[Node<T>], mut idx: usize) -> Path<T> {
+  let cost = nodes[idx].cost;
+  let mut states = vec![nodes[idx].state.clone()];
+  while let Some(parent) = nodes[idx].parent {
+    states.push(nodes[parent].state.clone());
+    idx = parent;
+  }
+  states.reverse();
+  Path { states, cost }
+}
+
+/// Breadth-first search, finding the path with the fewest steps. Step costs only add up to
+/// the reported [`Path::cost`].
+pub fn bfs<S: SearchSpace>(space: &S, start: S::State) -> Outcome<S::State> {
+  multi_source_bfs(space, [start])
+}
+
+/// Breadth-first search from several starts at once, finding the fewest steps from any of them.
+pub fn multi_source_bfs<S: SearchSpace>(
+  space: &S,
+  starts: impl IntoIterator<Item = S::State>,
+) -> Outcome<S::State> {
+  let mut stats = Stats::default();
+  let mut nodes = vec![];
+  let mut seen = HashSet::new();
+  let mut frontier = VecDeque::new();
+
+  for start in starts {
+    if seen.insert(start.clone()) {
+      frontier.push_back(nodes.len());
+      nodes.push(Node {
+        state: start,
+        parent: None,
+        cost: 0,
+      });
+    }
+  }
+
+  while let Some(idx) = frontier.pop_front() {
+    stats.expanded += 1;
+    if space.is_goal(&nodes[idx].state) {
+      return Outcome {
+        path: Some(reconstruct(&nodes, idx)),
+        stats,
+      };
+    }
+    for next in space.successors(&nodes[idx].state) {
+      stats.generated += 1;
+      if seen.insert(next.clone()) {
+        frontier.push_back(nodes.len());
+        let cost = nodes[idx].cost + space.cost(&nodes[idx].state, &next);
+        nodes.push(Node {
+          state: next,
+          parent: Some(idx),
+          cost,
+        });
+      }
+    }
+    stats.max_frontier = stats.max_frontier.max(frontier.len());
+  }
+
+  Outcome { path: None, stats }
+}
+
+fn best_first<S: SearchSpace>(space: &S, start: S::State, heuristic: bool) -> Outcome<S::State> {
+  let estimate = |state: &S::State| if heuristic { space.heuristic(state) } else { 0 };
+  let mut stats = Stats::default();
+  let mut frontier = BinaryHeap::from([Reverse((estimate(&start), 0, 0))]);
+  let mut best = HashMap::from([(start.clone(), 0)]);
+  let mut nodes = vec![Node {
+    state: start,
+    parent: None,
+    cost: 0,
+  }];
+
+  while let Some(Reverse((_, cost, idx))) = frontier.pop() {
+    if best[&nodes[idx].state] != idx {
+      continue;
+    }
+    stats.expanded += 1;
+    if space.is_goal(&nodes[idx].state) {
+      return Outcome {
+        path: Some(reconstruct(&nodes, idx)),
+        stats,
+      };
+    }
+    for next in space.successors(&nodes[idx].state) {
+      stats.generated += 1;
+      let next_cost = cost + space.cost(&nodes[idx].state, &next);
+      if matches!(best.get(&next), Some(&known) if nodes[known].cost <= next_cost) {
+        continue;
+      }
+      best.insert(next.clone(), nodes.len());
+      frontier.push(Reverse((
+        next_cost + estimate(&next),
+        next_cost,
+        nodes.len(),
+      )));
+      nodes.push(Node {
+        state: next,
+        parent: Some(idx),
+        cost: next_cost,
+      });
+    }
+    stats.max_frontier = stats.max_frontier.max(frontier.len());
+  }
+
+  Outcome { path: None, stats }
+}
+
+/// Dijkstra's algorithm, finding the cheapest path.
+pub fn dijkstra<S: SearchSpace>(space: &S, start: S::State) -> Outcome<S::State> {
+  best_first(space, start, false)
+}
+
+/// A*, finding the cheapest path guided by [`SearchSpace::heuristic`].
+pub fn astar<S: SearchSpace>(space: &S, start: S::State) -> Outcome<S::State> {
+  best_first(space, start, true)
+}
+
+/// Every state reachable from `start`, including `start` itself. Goals are not checked.
+pub fn flood_fill<S: SearchSpace>(space: &S, start: S::State) -> (HashSet<S::State>, Stats) {
+  let mut stats = Stats::default();
+  let mut seen = HashSet::from([start.clone()]);
+  let mut stack = vec![start];
+
+  while let Some(state) = stack.pop() {
+    stats.expanded += 1;
+    for next in space.successors(&state) {
+      stats.generated += 1;
+      if seen.insert(next.clone()) {
+        stack.push(next);
+      }
+    }
+    stats.max_frontier = stats.max_frontier.max(stack.len());
+  }
+
+  (seen, stats)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// A 5x5 grid with a wall in the middle column, open only at the bottom. Moving down costs 3.
+  struct Maze;
+
+  impl SearchSpace for Maze {
+    type State = (i32, i32);
+
+    fn successors(&self, &(x, y): &Self::State) -> Vec<Self::State> {
+      [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+        .into_iter()
+        .filter(|&(x, y)| (0..5).contains(&x) && (0..5).contains(&y))
+        .filter(|&(x, y)| x != 2 || y == 4)
+        .collect()
+    }
+
+    fn cost(&self, from: &Self::State, to: &Self::State) -> u64 {
+      if to.1 > from.1 {
+        3
+      } else {
+        1
+      }
+    }
+
+    fn heuristic(&self, &(x, y): &Self::State) -> u64 {
+      (x.abs_diff(4) + y.abs_diff(0)) as u64
+    }
+
+    fn is_goal(&self, state: &Self::State) -> bool {
+      *state == (4, 0)
+    }
+  }
+
+  #[test]
+  fn test_searches() {
+    let bfs = bfs(&Maze, (0, 0)).path.unwrap();
+    assert_eq!(bfs.states.len(), 13);
+
+    let dijkstra = dijkstra(&Maze, (0, 0));
+    let astar = astar(&Maze, (0, 0));
+    assert_eq!(dijkstra.path.as_ref().unwrap().cost, 20);
+    assert_eq!(astar.path.as_ref().unwrap().cost, 20);
+    assert!(astar.stats.expanded <= dijkstra.stats.expanded);
+
+    let multi = multi_source_bfs(&Maze, [(0, 0), (3, 3)]).path.unwrap();
+    assert_eq!(multi.states.first(), Some(&(3, 3)));
+    assert_eq!(multi.states.len(), 5);
+  }
+
+  #[test]
+  fn test_flood_fill() {
+    let (reached, stats) = flood_fill(&Maze, (0, 0));
+    assert_eq!(reached.len(), 21);
+    assert_eq!(stats.expanded, 21);
+  }
+}
@@ -0,0 +1,154 @@
+//! How far along every day is, gathered from the registry, the sample tests, the stored
+//! answers and the history of runs.
+
+use std::{collections::HashMap, fmt::Display, time::Duration};
+
+/// Name of the test checking a part on the sample input, under `dayN::tests::`.
+const SAMPLE_TEST: &str = "test_solve_part_";
+
+/// The result of a part's sample test.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sample {
+  Passed,
+  Failed,
+  Ignored,
+}
+
+/// The results of the sample tests in the output of `cargo test`, by day and part.
+pub fn sample_results(output: &str) -> HashMap<(u8, u8), Sample> {
+  output
+    .lines()
+    .filter_map(|line| {
+      let (name, result) = line.strip_prefix("test ")?.split_once(" ... ")?;
+      let (day, test) = name.split_once("::tests::")?;
+      let day = day.strip_prefix("day")?.parse().ok()?;
+      let part = test.strip_prefix(SAMPLE_TEST)?.parse().ok()?;
+      let sample = match result.trim() {
+        "ok" => Sample::Passed,
+        "FAILED" => Sample::Failed,
+        result if result.starts_with("ignored") => Sample::Ignored,
+        _ => return None,
+      };
+      Some(((day, part), sample))
+    })
+    .collect()
+}
+
+/// Where a part stands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Part {
+  pub implemented: bool,
+  /// `None` if there is no sample test, or the tests were not run.
+  pub sample: Option<Sample>,
+  pub answer_stored: bool,
+  /// How long the latest run recorded with the current build profile took.
+  pub duration: Option<Duration>,
+}
+
+impl Part {
+  /// Whether the part earned its star: implemented and right on the sample.
+  pub fn is_done(&self) -> bool {
+    self.implemented && self.sample == Some(Sample::Passed)
+  }
+}
+
+/// Where a day and both of its parts stand.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Day {
+  pub day: u8,
+  pub title: &'static str,
+  pub parts: [Part; 2],
+}
+
+/// Every day as a row of an Advent calendar, with a star for each part done.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Calendar(pub Vec<Day>);
+
+fn mark(yes: bool) -> char {
+  if yes {
+    '✓'
+  } else {
+    '·'
+  }
+}
+
+impl Display for Calendar {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    writeln!(f, "day      {:<29}{:<29} title", "part 1", "part 2")?;
+    writeln!(f, "         {0:<29}{0}", "impl sample answer time")?;
+    for day in &self.0 {
+      let stars = day
+        .parts
+        .iter()
+        .map(|part| if part.is_done() { '★' } else { '☆' })
+        .collect::<String>();
+      write!(f, "{:>3}  {stars}  ", day.day)?;
+      for part in &day.parts {
+        let sample = match part.sample {
+          Some(Sample::Passed) => '✓',
+          Some(Sample::Failed) => '✗',
+          Some(Sample::Ignored) => '~',
+          None => '·',
+        };
+        let duration = part
+          .duration
+          .map_or("·".to_string(), |duration| format!("{duration:.1?}"));
+        write!(
+          f,
+          "{:<4} {sample:<6} {:<6} {duration:<8}  ",
+          mark(part.implemented),
+          mark(part.answer_stored)
+        )?;
+      }
+      writeln!(f, " {}", day.title)?;
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_sample_results() {
+    let output = "running 4 tests
+test day22::tests::test_solve_part_2 ... FAILED
+test day24::tests::test_solve_part_2 ... ignored, later
+test day3::tests::test_solve_part_1 ... ok
+test day7::tests::test_explain ... ok
+test grid::tests::test_bounds ... ok";
+
+    assert_eq!(
+      sample_results(output),
+      HashMap::from([
+        ((22, 2), Sample::Failed),
+        ((24, 2), Sample::Ignored),
+        ((3, 1), Sample::Passed),
+      ])
+    );
+  }
+
+  #[test]
+  fn test_display() {
+    let calendar = Calendar(vec![Day {
+      day: 25,
+      title: "Full of Hot Air",
+      parts: [
+        Part {
+          implemented: true,
+          sample: Some(Sample::Passed),
+          answer_stored: true,
+          duration: Some(Duration::from_micros(1500)),
+        },
+        Part::default(),
+      ],
+    }]);
+
+    let row = calendar.to_string().lines().nth(2).unwrap().to_string();
+    assert_eq!(
+      row,
+      " 25  ★☆  ✓    ✓      ✓      1.5ms     ·    ·      ·      ·          Full of Hot Air"
+    );
+  }
+}
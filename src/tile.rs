@@ -0,0 +1,75 @@
+//! Puzzle grids drawn with one character per tile.
+//!
+//! `#[derive(Tile)]` maps each variant of an enum to its character, so parsing and printing a
+//! grid stay in sync.
+
+use std::fmt::Display;
+
+pub use aoc_derive::Tile;
+
+/// A character that is not one of the tiles of a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownTile {
+  /// The name of the tile type.
+  pub tile: &'static str,
+  pub found: char,
+}
+
+impl Display for UnknownTile {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{:?} is not a {} tile", self.found, self.tile)
+  }
+}
+
+impl std::error::Error for UnknownTile {}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[derive(Debug, Clone, Copy, PartialEq, Eq, Tile)]
+  enum Arrow {
+    #[tile('<')]
+    Left,
+    #[tile('>')]
+    Right,
+  }
+
+  #[derive(Debug, Clone, Copy, PartialEq, Eq, Tile)]
+  enum Cell {
+    #[tile('#')]
+    Wall,
+    Arrow(Arrow),
+  }
+
+  #[test]
+  fn test_round_trip() {
+    for &cell in Cell::ALL {
+      let c = cell.to_string().chars().next().unwrap();
+      assert_eq!(Cell::try_from(c), Ok(cell));
+    }
+    assert_eq!(
+      Cell::ALL,
+      [
+        Cell::Wall,
+        Cell::Arrow(Arrow::Left),
+        Cell::Arrow(Arrow::Right)
+      ]
+    );
+  }
+
+  #[test]
+  fn test_unknown() {
+    assert_eq!(
+      Cell::try_from('x'),
+      Err(UnknownTile {
+        tile: "Cell",
+        found: 'x'
+      })
+    );
+    assert_eq!(
+      Cell::try_from('x').unwrap_err().to_string(),
+      "'x' is not a Cell tile"
+    );
+  }
+}
@@ -0,0 +1,204 @@
+//! Puzzle inputs encrypted with a key kept outside the repository.
+//!
+//! Every input `path` may instead be committed as `path.enc`, holding a random nonce followed
+//! by the AES-256-GCM ciphertext. [`Vault::read`] prefers the plain file when there is one,
+//! so encrypted and plain inputs can be mixed while migrating.
+//!
+//! The tests only solve the samples, which stay in plain text, so they run without the key.
+
+use std::{
+  fmt::Display,
+  fs, io,
+  path::{Path, PathBuf},
+};
+
+use aes_gcm::{
+  aead::{Aead, AeadCore, KeyInit, OsRng},
+  Aes256Gcm, Key, Nonce,
+};
+
+use crate::config::{Config, Secret, SecretError};
+
+const NONCE_SIZE: usize = 12;
+
+/// Why an input could not be read or written.
+#[derive(Debug)]
+pub enum VaultError {
+  Io(PathBuf, io::Error),
+  /// Neither the plain file nor an encrypted one exists.
+  Missing(PathBuf),
+  /// The input is encrypted but no key is set.
+  NoKey(PathBuf),
+  /// The key is not 64 hexadecimal digits.
+  InvalidKey,
+  /// The key is wrong, or the file was tampered with.
+  Decryption(PathBuf),
+}
+
+impl Display for VaultError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      VaultError::Io(path, err) => write!(f, "{}: {err}", path.display()),
+      VaultError::Missing(path) => write!(f, "{} does not exist", path.display()),
+      VaultError::NoKey(path) => write!(f, "{} is encrypted but no key is set", path.display()),
+      VaultError::InvalidKey => write!(f, "the key is not 64 hexadecimal digits"),
+      VaultError::Decryption(path) => write!(f, "cannot decrypt {}", path.display()),
+    }
+  }
+}
+
+impl std::error::Error for VaultError {}
+
+/// Where the encrypted version of `path` lives.
+pub fn encrypted_path(path: impl AsRef<Path>) -> PathBuf {
+  let mut encrypted = path.as_ref().as_os_str().to_owned();
+  encrypted.push(".enc");
+  PathBuf::from(encrypted)
+}
+
+/// A new random key, as the hexadecimal digits [`Vault::from_secret`] expects.
+pub fn generate_key() -> String {
+  hex::encode(Aes256Gcm::generate_key(OsRng))
+}
+
+/// Reads and writes inputs, with or without a key.
+pub struct Vault {
+  cipher: Option<Aes256Gcm>,
+}
+
+impl Vault {
+  /// A vault that can only read plain inputs.
+  pub fn locked() -> Self {
+    Self { cipher: None }
+  }
+
+  /// A vault using the key in hexadecimal digits.
+  pub fn with_key(key: &str) -> Result<Self, VaultError> {
+    let key = hex::decode(key.trim()).map_err(|_| VaultError::InvalidKey)?;
+    if key.len() != 32 {
+      return Err(VaultError::InvalidKey);
+    }
+    Ok(Self {
+      cipher: Some(Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key))),
+    })
+  }
+
+  /// A vault using the key from `secret`, locked if the key is not set.
+  pub fn from_secret(secret: &Secret) -> Result<Self, VaultError> {
+    let key = secret
+      .value()
+      .map_err(|SecretError { path, err }| VaultError::Io(path, err))?;
+    match key {
+      Some(key) => Self::with_key(&key),
+      None => Ok(Self::locked()),
+    }
+  }
+
+  pub fn is_locked(&self) -> bool {
+    self.cipher.is_none()
+  }
+
+  /// The input at `path`, decrypting `path.enc` if the plain file is missing.
+  pub fn read(&self, path: impl AsRef<Path>) -> Result<String, VaultError> {
+    let path = path.as_ref();
+    match fs::read_to_string(path) {
+      Ok(input) => return Ok(input),
+      Err(err) if err.kind() != io::ErrorKind::NotFound => {
+        return Err(VaultError::Io(path.to_path_buf(), err))
+      }
+      Err(_) => (),
+    }
+
+    let encrypted_path = encrypted_path(path);
+    let encrypted = match fs::read(&encrypted_path) {
+      Ok(encrypted) => encrypted,
+      Err(err) if err.kind() == io::ErrorKind::NotFound => {
+        return Err(VaultError::Missing(path.to_path_buf()))
+      }
+      Err(err) => return Err(VaultError::Io(encrypted_path, err)),
+    };
+    let cipher = self
+      .cipher
+      .as_ref()
+      .ok_or_else(|| VaultError::NoKey(encrypted_path.clone()))?;
+    if encrypted.len() < NONCE_SIZE {
+      return Err(VaultError::Decryption(encrypted_path));
+    }
+    let (nonce, ciphertext) = encrypted.split_at(NONCE_SIZE);
+    cipher
+      .decrypt(Nonce::from_slice(nonce), ciphertext)
+      .ok()
+      .and_then(|plain| String::from_utf8(plain).ok())
+      .ok_or(VaultError::Decryption(encrypted_path))
+  }
+
+  /// Encrypts the plain input at `path` into `path.enc` and returns where it went. The plain
+  /// file is left alone.
+  pub fn encrypt(&self, path: impl AsRef<Path>) -> Result<PathBuf, VaultError> {
+    let path = path.as_ref();
+    let cipher = self
+      .cipher
+      .as_ref()
+      .ok_or_else(|| VaultError::NoKey(path.to_path_buf()))?;
+    let plain = fs::read(path).map_err(|err| VaultError::Io(path.to_path_buf(), err))?;
+
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+      .encrypt(&nonce, plain.as_slice())
+      .expect("puzzle inputs are far below the AES-GCM size limit");
+    let encrypted_path = encrypted_path(path);
+    fs::write(&encrypted_path, [nonce.as_slice(), &ciphertext].concat())
+      .map_err(|err| VaultError::Io(encrypted_path.clone(), err))?;
+    Ok(encrypted_path)
+  }
+}
+
+/// The input of `day`, usually at `default`, read with the key and from the input directory
+/// set in `config`.
+pub fn read_input(config: &Config, day: u8, default: &str) -> Result<String, VaultError> {
+  Vault::from_secret(&config.vault)?.read(config.input_path(day, default))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn scratch(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-vault-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(name)
+  }
+
+  #[test]
+  fn test_round_trip() {
+    let path = scratch("round_trip");
+    fs::write(&path, "1\n2\n").unwrap();
+    let vault = Vault::with_key(&generate_key()).unwrap();
+    let encrypted = vault.encrypt(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(vault.read(&path).unwrap(), "1\n2\n");
+    assert!(matches!(
+      Vault::with_key(&generate_key()).unwrap().read(&path),
+      Err(VaultError::Decryption(_))
+    ));
+    assert!(matches!(
+      Vault::locked().read(&path),
+      Err(VaultError::NoKey(_))
+    ));
+    fs::remove_file(encrypted).unwrap();
+  }
+
+  #[test]
+  fn test_errors() {
+    let path = scratch("missing");
+    assert!(matches!(
+      Vault::locked().read(&path),
+      Err(VaultError::Missing(_))
+    ));
+    assert!(matches!(
+      Vault::with_key("abc"),
+      Err(VaultError::InvalidKey)
+    ));
+  }
+}
@@ -0,0 +1,354 @@
+//! Three-dimensional grids of unit cubes, such as the lava droplet of day 18.
+
+use std::{
+  array,
+  collections::{HashMap, HashSet},
+  marker::PhantomData,
+};
+
+use crate::search::{flood_fill, SearchSpace};
+
+/// A cube position as `[x, y, z]`.
+pub type Voxel = [i32; 3];
+
+/// Which voxels count as neighbours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+  /// The 6 voxels sharing a face.
+  Faces,
+  /// The 26 voxels sharing a face, an edge or a corner.
+  All,
+}
+
+impl Connectivity {
+  /// The offsets of the neighbours.
+  pub fn offsets(self) -> Vec<Voxel> {
+    (-1..=1)
+      .flat_map(|dz| (-1..=1).flat_map(move |dy| (-1..=1).map(move |dx| [dx, dy, dz])))
+      .filter(|offset| match self {
+        Connectivity::Faces => offset.iter().map(|d: &i32| d.abs()).sum::<i32>() == 1,
+        Connectivity::All => *offset != [0; 3],
+      })
+      .collect()
+  }
+
+  pub fn neighbours(self, [x, y, z]: Voxel) -> impl Iterator<Item = Voxel> {
+    self
+      .offsets()
+      .into_iter()
+      .map(move |[dx, dy, dz]| [x + dx, y + dy, z + dz])
+  }
+}
+
+/// A box of voxels, both corners included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+  pub min: Voxel,
+  pub max: Voxel,
+}
+
+impl Bounds {
+  /// The smallest box holding every voxel, if there is any.
+  pub fn of(voxels: impl IntoIterator<Item = Voxel>) -> Option<Self> {
+    voxels.into_iter().fold(None, |bounds, voxel| {
+      Some(match bounds {
+        None => Bounds {
+          min: voxel,
+          max: voxel,
+        },
+        Some(Bounds { min, max }) => Bounds {
+          min: array::from_fn(|i| min[i].min(voxel[i])),
+          max: array::from_fn(|i| max[i].max(voxel[i])),
+        },
+      })
+    })
+  }
+
+  pub fn contains(&self, voxel: Voxel) -> bool {
+    (0..3).all(|i| (self.min[i]..=self.max[i]).contains(&voxel[i]))
+  }
+
+  /// The box grown by `margin` voxels on every side.
+  pub fn grow(&self, margin: i32) -> Self {
+    Bounds {
+      min: self.min.map(|i| i - margin),
+      max: self.max.map(|i| i + margin),
+    }
+  }
+
+  /// Number of voxels along each axis.
+  pub fn size(&self) -> [usize; 3] {
+    array::from_fn(|i| (self.max[i] - self.min[i] + 1).max(0) as usize)
+  }
+
+  pub fn volume(&self) -> usize {
+    self.size().iter().product()
+  }
+}
+
+/// Storage for the filled voxels, with the queries shared by every storage.
+pub trait Voxels<T> {
+  fn get(&self, voxel: Voxel) -> Option<&T>;
+
+  fn insert(&mut self, voxel: Voxel, value: T);
+
+  fn remove(&mut self, voxel: Voxel) -> Option<T>;
+
+  /// Every filled voxel, in no particular order.
+  fn voxels(&self) -> Vec<Voxel>;
+
+  fn is_filled(&self, voxel: Voxel) -> bool {
+    self.get(voxel).is_some()
+  }
+
+  /// The smallest box holding every filled voxel, if there is any.
+  fn bounds(&self) -> Option<Bounds> {
+    Bounds::of(self.voxels())
+  }
+
+  /// Faces of filled voxels touching an empty one, including the faces of inner cavities.
+  fn surface_area(&self) -> usize {
+    self
+      .voxels()
+      .into_iter()
+      .flat_map(|voxel| Connectivity::Faces.neighbours(voxel))
+      .filter(|&side| !self.is_filled(side))
+      .count()
+  }
+
+  /// The empty voxels reachable through faces from outside of the filled ones, within one
+  /// voxel of their bounds.
+  fn exterior(&self) -> HashSet<Voxel>
+  where
+    Self: Sized,
+  {
+    let Some(bounds) = self.bounds().map(|bounds| bounds.grow(1)) else {
+      return HashSet::new();
+    };
+    let region = Region {
+      voxels: self,
+      bounds,
+      filled: false,
+      connectivity: Connectivity::Faces,
+      value: PhantomData,
+    };
+    flood_fill(&region, bounds.min).0
+  }
+
+  /// Faces of filled voxels touching the [`exterior`](Voxels::exterior), leaving out cavities.
+  fn exterior_surface_area(&self) -> usize
+  where
+    Self: Sized,
+  {
+    let exterior = self.exterior();
+    self
+      .voxels()
+      .into_iter()
+      .flat_map(|voxel| Connectivity::Faces.neighbours(voxel))
+      .filter(|side| exterior.contains(side))
+      .count()
+  }
+
+  /// The groups of filled voxels connected to each other, in no particular order.
+  fn components(&self, connectivity: Connectivity) -> Vec<HashSet<Voxel>>
+  where
+    Self: Sized,
+  {
+    let Some(bounds) = self.bounds() else {
+      return Vec::new();
+    };
+    let region = Region {
+      voxels: self,
+      bounds,
+      filled: true,
+      connectivity,
+      value: PhantomData,
+    };
+    let mut labelled = HashSet::new();
+    let mut components = Vec::new();
+    for voxel in self.voxels() {
+      if !labelled.contains(&voxel) {
+        let (component, _) = flood_fill(&region, voxel);
+        labelled.extend(component.iter().copied());
+        components.push(component);
+      }
+    }
+    components
+  }
+}
+
+/// The filled or the empty voxels within some bounds, as a graph for the searches.
+struct Region<'a, V, T> {
+  voxels: &'a V,
+  bounds: Bounds,
+  filled: bool,
+  connectivity: Connectivity,
+  value: PhantomData<T>,
+}
+
+impl<V: Voxels<T>, T> SearchSpace for Region<'_, V, T> {
+  type State = Voxel;
+
+  fn successors(&self, &voxel: &Voxel) -> Vec<Voxel> {
+    self
+      .connectivity
+      .neighbours(voxel)
+      .filter(|&next| self.bounds.contains(next) && self.voxels.is_filled(next) == self.filled)
+      .collect()
+  }
+}
+
+/// An unbounded grid keeping only its filled voxels.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Sparse<T>(pub HashMap<Voxel, T>);
+
+impl<T> Voxels<T> for Sparse<T> {
+  fn get(&self, voxel: Voxel) -> Option<&T> {
+    self.0.get(&voxel)
+  }
+
+  fn insert(&mut self, voxel: Voxel, value: T) {
+    self.0.insert(voxel, value);
+  }
+
+  fn remove(&mut self, voxel: Voxel) -> Option<T> {
+    self.0.remove(&voxel)
+  }
+
+  fn voxels(&self) -> Vec<Voxel> {
+    self.0.keys().copied().collect()
+  }
+}
+
+impl<T> FromIterator<(Voxel, T)> for Sparse<T> {
+  fn from_iter<I: IntoIterator<Item = (Voxel, T)>>(iter: I) -> Self {
+    Self(iter.into_iter().collect())
+  }
+}
+
+/// A grid storing every voxel of a box. Everything outside of it reads as empty, and filling
+/// a voxel there panics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dense<T> {
+  area: Bounds,
+  cells: Vec<Option<T>>,
+}
+
+impl<T> Dense<T> {
+  pub fn new(area: Bounds) -> Self {
+    Self {
+      area,
+      cells: std::iter::repeat_with(|| None)
+        .take(area.volume())
+        .collect(),
+    }
+  }
+
+  /// The box the grid can hold voxels in.
+  pub fn area(&self) -> Bounds {
+    self.area
+  }
+
+  fn index(&self, voxel: Voxel) -> Option<usize> {
+    if !self.area.contains(voxel) {
+      return None;
+    }
+    let [width, height, _] = self.area.size();
+    let [x, y, z]: [usize; 3] = array::from_fn(|i| (voxel[i] - self.area.min[i]) as usize);
+    Some((z * height + y) * width + x)
+  }
+
+  fn voxel(&self, index: usize) -> Voxel {
+    let [width, height, _] = self.area.size();
+    let offset = [
+      index % width,
+      index / width % height,
+      index / width / height,
+    ];
+    array::from_fn(|i| self.area.min[i] + offset[i] as i32)
+  }
+}
+
+impl<T> Voxels<T> for Dense<T> {
+  fn get(&self, voxel: Voxel) -> Option<&T> {
+    self.cells[self.index(voxel)?].as_ref()
+  }
+
+  fn insert(&mut self, voxel: Voxel, value: T) {
+    let index = self
+      .index(voxel)
+      .unwrap_or_else(|| panic!("{voxel:?} is outside of the grid"));
+    self.cells[index] = Some(value);
+  }
+
+  fn remove(&mut self, voxel: Voxel) -> Option<T> {
+    let index = self.index(voxel)?;
+    self.cells[index].take()
+  }
+
+  fn voxels(&self) -> Vec<Voxel> {
+    self
+      .cells
+      .iter()
+      .enumerate()
+      .filter(|(_, cell)| cell.is_some())
+      .map(|(index, _)| self.voxel(index))
+      .collect()
+  }
+}
+
+/// Sizes the grid to the bounds of the voxels.
+impl<T> FromIterator<(Voxel, T)> for Dense<T> {
+  fn from_iter<I: IntoIterator<Item = (Voxel, T)>>(iter: I) -> Self {
+    let filled = iter.into_iter().collect::<Vec<_>>();
+    let area = Bounds::of(filled.iter().map(|&(voxel, _)| voxel)).unwrap_or(Bounds {
+      min: [0; 3],
+      max: [-1; 3],
+    });
+    let mut grid = Self::new(area);
+    for (voxel, value) in filled {
+      grid.insert(voxel, value);
+    }
+    grid
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// A 3x3x3 cube with its center carved out.
+  fn hollow_cube() -> impl Iterator<Item = (Voxel, ())> {
+    (0..27)
+      .map(|i| [i % 3, i / 3 % 3, i / 9])
+      .filter(|&voxel| voxel != [1; 3])
+      .map(|voxel| (voxel, ()))
+  }
+
+  #[test]
+  fn test_surface_area() {
+    let dense = hollow_cube().collect::<Dense<_>>();
+    let sparse = hollow_cube().collect::<Sparse<_>>();
+
+    assert_eq!(dense.surface_area(), 54 + 6);
+    assert_eq!(dense.exterior_surface_area(), 54);
+    assert_eq!(sparse.surface_area(), 54 + 6);
+    assert_eq!(sparse.exterior_surface_area(), 54);
+    assert!(!dense.exterior().contains(&[1; 3]));
+    assert_eq!(dense.exterior().len(), 5 * 5 * 5 - 27);
+  }
+
+  #[test]
+  fn test_components() {
+    let mut grid = [[0, 0, 0], [1, 1, 1], [3, 0, 0], [4, 0, 0]]
+      .into_iter()
+      .map(|voxel| (voxel, ()))
+      .collect::<Dense<_>>();
+
+    assert_eq!(grid.components(Connectivity::Faces).len(), 3);
+    assert_eq!(grid.components(Connectivity::All).len(), 2);
+    assert_eq!(grid.remove([1, 1, 1]), Some(()));
+    assert_eq!(grid.voxels(), [[0, 0, 0], [3, 0, 0], [4, 0, 0]]);
+    assert_eq!(Connectivity::All.offsets().len(), 26);
+  }
+}